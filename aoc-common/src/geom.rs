@@ -0,0 +1,248 @@
+use std::{
+    num::TryFromIntError,
+    ops::{Add, Neg, Sub},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub width: usize,
+    pub height: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Coord {
+    pub fn new(row: usize, col: usize) -> Coord {
+        Coord { row, col }
+    }
+
+    pub fn offset(&self, by: Point) -> Option<Coord> {
+        Some(Coord {
+            row: self.row.checked_add_signed(by.row)?,
+            col: self.col.checked_add_signed(by.col)?,
+        })
+    }
+
+    pub fn step(&self, dir: Direction, bounds: Bounds) -> Option<Coord> {
+        self.offset(dir.offset()).filter(|c| bounds.contains(*c))
+    }
+
+    pub fn manhattan(&self, other: &Coord) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    pub fn chebyshev(&self, other: &Coord) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl Point {
+    pub fn new(row: isize, col: isize) -> Point {
+        Point { row, col }
+    }
+
+    pub fn step(&self, dir: Direction) -> Point {
+        *self + dir.offset()
+    }
+
+    pub fn manhattan(&self, other: &Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    pub fn chebyshev(&self, other: &Point) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl Bounds {
+    pub fn contains(&self, c: Coord) -> bool {
+        c.row < self.height && c.col < self.width
+    }
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn reverse(&self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    pub fn offset(&self) -> Point {
+        match self {
+            Direction::North => Point { row: -1, col: 0 },
+            Direction::East => Point { row: 0, col: 1 },
+            Direction::South => Point { row: 1, col: 0 },
+            Direction::West => Point { row: 0, col: -1 },
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point {
+            row: self.row + rhs.row,
+            col: self.col + rhs.col,
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point {
+            row: self.row - rhs.row,
+            col: self.col - rhs.col,
+        }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point {
+            row: -self.row,
+            col: -self.col,
+        }
+    }
+}
+
+impl TryFrom<Coord> for Point {
+    type Error = TryFromIntError;
+
+    fn try_from(c: Coord) -> Result<Point, Self::Error> {
+        Ok(Point {
+            row: c.row.try_into()?,
+            col: c.col.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Point> for Coord {
+    type Error = TryFromIntError;
+
+    fn try_from(p: Point) -> Result<Coord, Self::Error> {
+        Ok(Coord {
+            row: p.row.try_into()?,
+            col: p.col.try_into()?,
+        })
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(dir: Direction) -> Point {
+        dir.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geom::{Bounds, Coord, Direction, Point};
+
+    #[test]
+    fn test_step() {
+        let bounds = Bounds {
+            width: 3,
+            height: 2,
+        };
+        let c = Coord::new(0, 0);
+        assert_eq!(c.step(Direction::North, bounds), None);
+        assert_eq!(c.step(Direction::West, bounds), None);
+        assert_eq!(c.step(Direction::East, bounds), Some(Coord::new(0, 1)));
+        assert_eq!(c.step(Direction::South, bounds), Some(Coord::new(1, 0)));
+        assert_eq!(Coord::new(1, 2).step(Direction::South, bounds), None);
+        assert_eq!(Coord::new(1, 2).step(Direction::East, bounds), None);
+    }
+
+    #[test]
+    fn test_turn() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.reverse().offset(), -dir.offset());
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn test_distance() {
+        let c1 = Coord::new(6, 1);
+        let c2 = Coord::new(11, 5);
+        assert_eq!(c1.manhattan(&c2), 9);
+        assert_eq!(c1.chebyshev(&c2), 5);
+
+        let p1 = Point::new(-2, 3);
+        let p2 = Point::new(1, -1);
+        assert_eq!(p1.manhattan(&p2), 7);
+        assert_eq!(p1.chebyshev(&p2), 4);
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Point::try_from(Coord::new(2, 3)), Ok(Point::new(2, 3)));
+        assert_eq!(Coord::try_from(Point::new(2, 3)), Ok(Coord::new(2, 3)));
+        assert!(Coord::try_from(Point::new(-1, 3)).is_err());
+        assert_eq!(
+            Point::new(0, 0).step(Direction::North),
+            Point::from(Direction::North)
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use std::fmt::{Display, Write};
+
+use crate::{
+    geom::{Bounds, Coord, Direction, Point},
+    parse::ParseError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(cells: Vec<T>, width: usize) -> Option<Grid<T>> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+        let height = cells.len() / width;
+        Some(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let first = input.lines().next().unwrap_or("");
+        let width = first.chars().count();
+        if width == 0 {
+            return Err(ParseError::new(input, first, "a grid"));
+        }
+
+        let mut cells = vec![];
+        let mut height = 0;
+
+        for line in input.lines() {
+            if line.chars().count() != width {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("a line of {} characters", width),
+                ));
+            }
+            for (i, c) in line.char_indices() {
+                let found = &line[i..i + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| ParseError::new(input, found, expected))?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn contains(&self, c: Coord) -> bool {
+        self.bounds().contains(c)
+    }
+
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        if !self.contains(c) {
+            return None;
+        }
+        self.cells.get(c.row * self.width + c.col)
+    }
+
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        if !self.contains(c) {
+            return None;
+        }
+        self.cells.get_mut(c.row * self.width + c.col)
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.height).flat_map(|row| (0..self.width).map(move |col| Coord { row, col }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.iter().find(|(_, t)| pred(t)).map(|(c, _)| c)
+    }
+
+    pub fn row(&self, r: usize) -> Option<&[T]> {
+        self.cells.get(r * self.width..(r + 1) * self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn col(&self, c: usize) -> Option<impl Iterator<Item = &T>> {
+        if c >= self.width {
+            return None;
+        }
+        Some(self.cells.iter().skip(c).step_by(self.width))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|c| self.cells.iter().skip(c).step_by(self.width))
+    }
+
+    pub fn neighbours4(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| c.step(dir, self.bounds()))
+    }
+
+    pub fn neighbours8(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        (-1..=1)
+            .flat_map(|row| (-1..=1).map(move |col| Point { row, col }))
+            .filter(|p| *p != Point { row: 0, col: 0 })
+            .filter_map(move |p| c.offset(p))
+            .filter(|n| self.contains(*n))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = self.cols().flatten().cloned().collect();
+        Self {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let cells = self
+            .cols()
+            .flat_map(|col| col.collect::<Vec<_>>().into_iter().rev())
+            .cloned()
+            .collect();
+        Self {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|c| self.col(c).unwrap())
+            .cloned()
+            .collect();
+        Self {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geom::Coord, grid::Grid};
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(input, "any character", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid("abc\ndef\n");
+        assert_eq!(g.width(), 3);
+        assert_eq!(g.height(), 2);
+        assert_eq!(g.get(Coord::new(1, 2)), Some(&'f'));
+        assert_eq!(g.get(Coord::new(2, 0)), None);
+        assert_eq!(g.get(Coord::new(0, 3)), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Grid::parse("ab\nc", "any character", Some).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.expected, "a line of 2 characters");
+
+        let e = Grid::parse("", "any character", Some).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+
+        let e = Grid::parse("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+        assert_eq!(e.found, "x");
+        assert_eq!(e.expected, "a digit");
+    }
+
+    #[test]
+    fn test_rows_cols() {
+        let g = grid("abc\ndef");
+        let rows: Vec<String> = g.rows().map(|r| r.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        let cols: Vec<String> = g.cols().map(|c| c.collect()).collect();
+        assert_eq!(cols, vec!["ad", "be", "cf"]);
+        assert_eq!(g.col(1).unwrap().collect::<String>(), "be");
+        assert!(g.col(3).is_none());
+    }
+
+    #[test]
+    fn test_transpose_rotate() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(
+            g.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            g
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid("abc\ndef\nghi");
+        let n: Vec<_> = g.neighbours4(Coord::new(0, 0)).collect();
+        assert_eq!(n, vec![Coord::new(0, 1), Coord::new(1, 0)]);
+        assert_eq!(g.neighbours4(Coord::new(1, 1)).count(), 4);
+        assert_eq!(g.neighbours8(Coord::new(1, 1)).count(), 8);
+        assert_eq!(g.neighbours8(Coord::new(2, 2)).count(), 3);
+    }
+}
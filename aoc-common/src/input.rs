@@ -0,0 +1,64 @@
+use std::{
+    fs::read_to_string,
+    io::{self, Read},
+    path::Path,
+};
+
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+    read_to_string(path)
+}
+
+fn next_line(s: &str) -> (&str, &str) {
+    let end = s.find('\n').map(|i| i + 1).unwrap_or(s.len());
+    s.split_at(end)
+}
+
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        loop {
+            let (line, after) = next_line(rest);
+            if line.is_empty() {
+                return None;
+            }
+            if !line.trim().is_empty() {
+                break;
+            }
+            rest = after;
+        }
+
+        let (mut end, mut pos) = (0, 0);
+        while pos < rest.len() {
+            let (line, _) = next_line(&rest[pos..]);
+            if line.trim().is_empty() {
+                break;
+            }
+            end = pos + line.trim_end_matches(['\r', '\n']).len();
+            pos += line.len();
+        }
+
+        let block = &rest[..end];
+        rest = &rest[pos..];
+        Some(block)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::blocks;
+
+    #[test]
+    fn test_blocks() {
+        let found: Vec<_> = blocks("a\nb\n\nc\n\n\nd\n").collect();
+        assert_eq!(found, vec!["a\nb", "c", "d"]);
+        let found: Vec<_> = blocks("\r\nx\r\ny\r\n  \r\nz").collect();
+        assert_eq!(found, vec!["x\r\ny", "z"]);
+        assert_eq!(blocks("\n\n").count(), 0);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod solution;
@@ -0,0 +1,165 @@
+use std::{
+    fmt::Display,
+    io::{self, IsTerminal},
+    time::Duration,
+};
+
+use clap::ValueEnum;
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::solution::{Answer, PartResult, Report};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+struct JsonResult<'a> {
+    input: &'a str,
+    day: u8,
+    part: u8,
+    answer: Option<Answer>,
+    parse_ms: Option<f64>,
+    solve_ms: Option<f64>,
+    error: Option<&'a str>,
+}
+
+pub fn disable_colour_unless_terminal() {
+    if !io::stdout().is_terminal() {
+        colored::control::set_override(false);
+    }
+}
+
+pub fn print_answer(part: u8, answer: impl Display) {
+    println!("Part {}: {}", part, answer.to_string().green());
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn json_lines(input: &str, day: u8, parts: &[u8], report: &Result<Report, String>) -> Vec<String> {
+    let lines: Vec<JsonResult> = match report {
+        Ok(report) => report
+            .parts
+            .iter()
+            .map(|p| JsonResult {
+                input,
+                day,
+                part: p.part,
+                answer: p.answer.as_ref().ok().copied(),
+                parse_ms: Some(millis(report.parse_time)),
+                solve_ms: Some(millis(p.time)),
+                error: p.answer.as_ref().err().map(|e| e.as_str()),
+            })
+            .collect(),
+        Err(e) => parts
+            .iter()
+            .map(|part| JsonResult {
+                input,
+                day,
+                part: *part,
+                answer: None,
+                parse_ms: None,
+                solve_ms: None,
+                error: e.lines().next(),
+            })
+            .collect(),
+    };
+
+    lines
+        .iter()
+        .map(|line| serde_json::to_string(line).unwrap())
+        .collect()
+}
+
+pub fn print_report(
+    input: &str,
+    day: u8,
+    parts: &[u8],
+    report: &Result<Report, String>,
+    format: Format,
+) -> bool {
+    let ok = match report {
+        Ok(report) => report.parts.iter().all(|p| p.answer.is_ok()),
+        Err(_) => false,
+    };
+
+    match format {
+        Format::Json => {
+            for line in json_lines(input, day, parts, report) {
+                println!("{}", line);
+            }
+        }
+        Format::Text => match report {
+            Ok(report) => print_results(&report.parts),
+            Err(e) => eprintln!("error: {}", e),
+        },
+    }
+
+    ok
+}
+
+fn print_results(results: &[PartResult]) {
+    for PartResult { part, answer, .. } in results {
+        match answer {
+            Ok(answer) => print_answer(*part, answer),
+            Err(e) => eprintln!("Part {}: error: {}", part, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        output::json_lines,
+        solution::{Answer, PartResult, Report},
+    };
+
+    #[test]
+    fn test_json_lines() {
+        let report = Report {
+            parse_time: Duration::from_micros(1500),
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: Ok(Answer::Signed(-2)),
+                    time: Duration::from_millis(2),
+                },
+                PartResult {
+                    part: 2,
+                    answer: Err("no path".to_string()),
+                    time: Duration::from_millis(3),
+                },
+            ],
+        };
+        assert_eq!(
+            json_lines("input.txt", 8, &[1, 2], &Ok(report)),
+            vec![
+                r#"{"input":"input.txt","day":8,"part":1,"answer":-2,"parse_ms":1.5,"solve_ms":2.0,"error":null}"#,
+                r#"{"input":"input.txt","day":8,"part":2,"answer":null,"parse_ms":1.5,"solve_ms":3.0,"error":"no path"}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_lines_parse_error() {
+        assert_eq!(
+            json_lines(
+                "-",
+                8,
+                &[2],
+                &Err("line 3, column 1: expected a node name\n  |\n3 | = (A, B)".to_string())
+            ),
+            vec![
+                r#"{"input":"-","day":8,"part":2,"answer":null,"parse_ms":null,"solve_ms":null,"error":"line 3, column 1: expected a node name"}"#
+            ]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+    source: String,
+    offset: usize,
+}
+
+fn offset_in(outer: &str, inner: &str) -> usize {
+    let start = outer.as_ptr() as usize;
+    let at = inner.as_ptr() as usize;
+    if at >= start && at + inner.len() <= start + outer.len() {
+        at - start
+    } else {
+        0
+    }
+}
+
+impl ParseError {
+    pub fn new(input: &str, found: &str, expected: impl Into<String>) -> ParseError {
+        let offset = offset_in(input, found);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let source = input[line_start..].lines().next().unwrap_or("");
+        let found = found.lines().next().unwrap_or("");
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            found: found.to_string(),
+            expected: expected.into(),
+            source: source.to_string(),
+            offset,
+        }
+    }
+
+    pub fn within(self, outer: &str, context: &str) -> ParseError {
+        let start = offset_in(outer, context) + self.offset;
+        let end = (start + self.found.len()).min(outer.len());
+        Self::new(outer, &outer[start..end], self.expected)
+    }
+}
+
+pub fn number<T: FromStr>(input: &str, s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(input, s, "a number"))
+}
+
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    delimiter: char,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, s, format!("'{}'", delimiter)))
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            f.write_str("found nothing")?;
+        } else {
+            write!(f, "found '{}'", self.found)?;
+        }
+
+        if f.alternate() {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let carets = "^".repeat(self.found.chars().count().max(1));
+            write!(
+                f,
+                "\n{} |\n{} | {}\n{} | {}{}",
+                gutter,
+                self.line,
+                self.source,
+                gutter,
+                " ".repeat(self.column - 1),
+                carets
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{number, ParseError};
+
+    #[test]
+    fn test_location() {
+        let input = "seeds: 79 14\nsoil: 1 x2 3\n";
+        let at = input.find("x2").unwrap();
+        let e = ParseError::new(input, &input[at..at + 2], "a number");
+        assert_eq!((e.line, e.column), (2, 9));
+        assert_eq!(e.found, "x2");
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 9: expected a number, found 'x2'"
+        );
+        assert_eq!(
+            format!("{:#}", e),
+            concat!(
+                "line 2, column 9: expected a number, found 'x2'\n",
+                "  |\n",
+                "2 | soil: 1 x2 3\n",
+                "  |         ^^"
+            )
+        );
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "1 2\n3 x 4";
+        let line = input.lines().nth(1).unwrap();
+        let e = number::<u32>(line, &line[2..3]).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 3));
+        let e = e.within(input, line);
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.found, "x");
+    }
+
+    #[test]
+    fn test_not_in_input() {
+        let e = ParseError::new("abc", "", "a line");
+        assert_eq!((e.line, e.column), (1, 1));
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 1: expected a line, found nothing"
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+use serde::Serialize;
+
+use crate::{
+    input::read_input,
+    output::{disable_colour_unless_terminal, print_report, Format},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+}
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Error: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error>;
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Result<Answer, String>,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+pub type Runner = fn(input: &str, parts: &[u8]) -> Result<Report, String>;
+
+pub fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<Report, String> {
+    let start = Instant::now();
+    let input = S::parse(input).map_err(|e| format!("{:#}", e))?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            };
+            PartResult {
+                part: *part,
+                answer: answer.map_err(|e| e.to_string()),
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report { parse_time, parts })
+}
+
+#[derive(Parser)]
+pub struct Args {
+    /// Puzzle input files, - reads stdin
+    #[arg(required = true)]
+    pub inputs: Vec<PathBuf>,
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+pub fn solve_files(
+    day: u8,
+    runner: Runner,
+    paths: &[PathBuf],
+    parts: &[u8],
+    format: Format,
+) -> bool {
+    let mut ok = true;
+
+    for (i, path) in paths.iter().enumerate() {
+        let name = path.display().to_string();
+        if format == Format::Text && paths.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("{}:", name);
+        }
+
+        let report = read_input(path)
+            .map_err(|e| format!("{}: {}", name, e))
+            .and_then(|input| runner(&input, parts));
+        ok &= print_report(&name, day, parts, &report, format);
+    }
+
+    ok
+}
+
+// Hands every parsed input to `f` instead of solving, for flags that print
+// something else about the inputs. Errors go to stderr and the other inputs
+// still get their turn.
+pub fn for_each_input<S: Solution>(
+    paths: &[PathBuf],
+    mut f: impl FnMut(&str, &S::Input) -> Result<(), String>,
+) -> ExitCode {
+    let mut ok = true;
+
+    for (i, path) in paths.iter().enumerate() {
+        let name = path.display().to_string();
+        if paths.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("{}:", name);
+        }
+
+        let result = read_input(path)
+            .map_err(|e| format!("{}: {}", name, e))
+            .and_then(|input| S::parse(&input).map_err(|e| format!("{:#}", e)))
+            .and_then(|input| f(&name, &input));
+        if let Err(e) = result {
+            eprintln!("error: {}", e);
+            ok = false;
+        }
+    }
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+pub fn main<S: Solution>() -> ExitCode {
+    main_with::<S>(&Args::parse())
+}
+
+pub fn main_with<S: Solution>(args: &Args) -> ExitCode {
+    disable_colour_unless_terminal();
+
+    if solve_files(S::DAY, run::<S>, &args.inputs, &[1, 2], args.format) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Self::Unsigned(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Self::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Self::Signed(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Display, process::ExitCode};
+
+    use crate::solution::{for_each_input, run, Answer, Solution};
+
+    struct Sum;
+
+    struct NotANumber(String);
+
+    impl Display for NotANumber {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "not a number: {}", self.0)
+        }
+    }
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<i64>;
+        type Error = NotANumber;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            input
+                .lines()
+                .map(|l| l.parse().map_err(|_| NotANumber(l.to_string())))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+            Err(NotANumber(input.len().to_string()))
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let report = run::<Sum>("1\n-3\n", &[1, 2]).unwrap();
+        let results: Vec<_> = report
+            .parts
+            .into_iter()
+            .map(|r| (r.part, r.answer))
+            .collect();
+        assert_eq!(results[0], (1, Ok(Answer::Signed(-2))));
+        assert_eq!(results[1], (2, Err("not a number: 2".to_string())));
+    }
+
+    #[test]
+    fn test_for_each_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-for-each-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let paths: Vec<_> = [("good", "1\n2\n"), ("bad", "1\nx\n"), ("empty", "")]
+            .into_iter()
+            .map(|(name, input)| {
+                let path = dir.join(name);
+                std::fs::write(&path, input).unwrap();
+                path
+            })
+            .chain([dir.join("missing")])
+            .collect();
+
+        let mut seen = vec![];
+        let code = for_each_input::<Sum>(&paths, |name, input| {
+            seen.push((name.to_string(), input.clone()));
+            if input.is_empty() {
+                Err(format!("{}: nothing to sum", name))
+            } else {
+                Ok(())
+            }
+        });
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(code, ExitCode::FAILURE);
+        let names: Vec<_> = paths.iter().map(|p| p.display().to_string()).collect();
+        assert_eq!(
+            seen,
+            vec![(names[0].clone(), vec![1, 2]), (names[2].clone(), vec![])]
+        );
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        assert_eq!(
+            run::<Sum>("1\nx\n", &[1]),
+            Err("not a number: x".to_string())
+        );
+    }
+}
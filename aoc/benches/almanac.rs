@@ -0,0 +1,112 @@
+use std::{ops::Range, str::FromStr};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day5::{Map, RangeSet};
+
+// Cuts 0..size * 1000 into `size` blocks and sends them to shuffled places,
+// like the generated almanacs do.
+fn generate(size: u64) -> Vec<(u64, u64, u64)> {
+    let mut state: u64 = 0x2545f4914f6cdd1d;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        state >> 33
+    };
+
+    let mut destinations: Vec<u64> = (0..size).map(|i| i * 1000).collect();
+    for i in (1..destinations.len()).rev() {
+        destinations.swap(i, random() as usize % (i + 1));
+    }
+    destinations
+        .into_iter()
+        .enumerate()
+        .map(|(i, destination)| (destination, i as u64 * 1000, 1 + random() % 999))
+        .collect()
+}
+
+fn linear_apply(ranges: &[(u64, u64, u64)], input: u64) -> u64 {
+    ranges
+        .iter()
+        .find(|(_, source, len)| (*source..source + len).contains(&input))
+        .map_or(input, |(destination, source, _)| {
+            destination + (input - source)
+        })
+}
+
+// What apply_ranges did before the sweep: every range cuts every fragment
+// that is still unmapped.
+fn split_apply_ranges(ranges: &[(u64, u64, u64)], spans: &[Range<u64>]) -> RangeSet {
+    let mut mapped = vec![];
+    let mut unmatched = spans.to_vec();
+    for &(destination, source, len) in ranges {
+        let mut rest = vec![];
+        for span in unmatched {
+            let (start, end) = (span.start.max(source), span.end.min(source + len));
+            if start >= end {
+                rest.push(span);
+                continue;
+            }
+            mapped.push(destination + (start - source)..destination + (end - source));
+            if span.start < start {
+                rest.push(span.start..start);
+            }
+            if end < span.end {
+                rest.push(end..span.end);
+            }
+        }
+        unmatched = rest;
+    }
+    mapped.into_iter().chain(unmatched).collect()
+}
+
+fn almanac(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day5/map");
+    for size in [100, 1_000, 20_000] {
+        let ranges = generate(size);
+        let text: String = ranges
+            .iter()
+            .map(|(d, s, l)| format!("\n{} {} {}", d, s, l))
+            .collect();
+        let map = Map::<u64>::from_str(&format!("a-to-b map:{}", text)).unwrap();
+
+        let inputs: Vec<u64> = (0..1000).map(|i| i * size * 997 % (size * 1000)).collect();
+        group.bench_with_input(
+            BenchmarkId::new("apply/linear", size),
+            &inputs,
+            |b, inputs| {
+                b.iter(|| {
+                    inputs
+                        .iter()
+                        .map(|&i| linear_apply(&ranges, black_box(i)))
+                        .min()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("apply/sorted", size),
+            &inputs,
+            |b, inputs| b.iter(|| inputs.iter().map(|&i| map.apply(black_box(i))).min()),
+        );
+
+        let spans: RangeSet = inputs.iter().map(|&i| i..i + 500).collect();
+        assert_eq!(
+            split_apply_ranges(&ranges, spans.ranges()),
+            map.apply_ranges(&spans)
+        );
+        group.bench_with_input(
+            BenchmarkId::new("apply_ranges/split", size),
+            &spans,
+            |b, spans| b.iter(|| split_apply_ranges(&ranges, black_box(spans.ranges()))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("apply_ranges/sweep", size),
+            &spans,
+            |b, spans| b.iter(|| map.apply_ranges(black_box(spans))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, almanac);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+use aoc_common::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day7::{ranked, Day7};
+
+fn generate(count: usize) -> String {
+    let cards: Vec<char> = "23456789TJQKA".chars().collect();
+    let mut state: u64 = 0x9e3779b97f4a7c15;
+    let mut random = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize
+    };
+
+    (0..count)
+        .map(|_| {
+            let hand: String = (0..5).map(|_| cards[random() % cards.len()]).collect();
+            format!("{} {}\n", hand, 1 + random() % 1000)
+        })
+        .collect()
+}
+
+fn camel_cards(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day7/rank");
+    group.sample_size(10);
+    for count in [1_000, 100_000, 1_000_000] {
+        let input = generate(count);
+        let bids = Day7::parse(&input).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("parse", count), &input, |b, input| {
+            b.iter(|| Day7::parse(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("sort_key", count), &bids, |b, bids| {
+            b.iter(|| ranked(black_box(&bids.with_joker)).len())
+        });
+        // what every comparison used to cost, re-classifying both hands, too
+        // slow to sample at a million
+        if count > 100_000 {
+            continue;
+        }
+        group.bench_with_input(
+            BenchmarkId::new("kind_per_comparison", count),
+            &bids,
+            |b, bids| {
+                b.iter(|| {
+                    let mut sorted: Vec<_> = black_box(&bids.with_joker).iter().collect();
+                    sorted.sort_by(|a, b| a.kind().cmp(&b.kind()).then_with(|| a.cmp(b)));
+                    sorted.len()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, camel_cards);
+criterion_main!(benches);
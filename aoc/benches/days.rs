@@ -0,0 +1,47 @@
+use std::{fs, path::Path};
+
+use aoc_common::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_input<S: Solution>(c: &mut Criterion, day: u8, label: &str, input: &str) {
+    let Ok(parsed) = S::parse(input) else {
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("day{}/{}", day, label));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, example: &str) {
+    bench_input::<S>(c, day, "example", example);
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../inputs")
+        .join(format!("day{}.txt", day));
+    if let Ok(input) = fs::read_to_string(path) {
+        bench_input::<S>(c, day, "real", &input);
+    }
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day2::Day2>(c, 2, day2::EXAMPLE);
+    bench_day::<day4::Day4>(c, 4, day4::EXAMPLE);
+    bench_day::<day5::Day5>(c, 5, day5::EXAMPLE);
+    bench_day::<day6::Day6>(c, 6, day6::EXAMPLE);
+    bench_day::<day7::Day7>(c, 7, day7::EXAMPLE);
+    bench_day::<day8::Day8>(c, 8, day8::EXAMPLE);
+    bench_day::<day9::Day9>(c, 9, day9::EXAMPLE);
+    bench_day::<day10::Day10>(c, 10, day10::EXAMPLE);
+    bench_day::<day11::Day11>(c, 11, day11::EXAMPLE);
+    bench_day::<day12::Day12>(c, 12, day12::EXAMPLE);
+    bench_day::<day13::Day13>(c, 13, day13::EXAMPLE);
+    bench_day::<day14::Day14>(c, 14, day14::EXAMPLE);
+    bench_day::<day15::Day15>(c, 15, day15::EXAMPLE);
+    bench_day::<day16::Day16>(c, 16, day16::EXAMPLE);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
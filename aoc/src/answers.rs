@@ -0,0 +1,84 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, BTreeMap<u8, String>>,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers, String> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        let raw: BTreeMap<String, BTreeMap<String, Expected>> =
+            toml::from_str(content).map_err(|e| e.message().to_string())?;
+        let mut days = BTreeMap::new();
+
+        for (day_key, parts) in raw {
+            let day = number_from_key(&day_key, "day")?;
+            let mut expected = BTreeMap::new();
+            for (part_key, value) in parts {
+                let part = number_from_key(&part_key, "part")?;
+                let value = match value {
+                    Expected::Number(n) => n.to_string(),
+                    Expected::Text(s) => s,
+                };
+                expected.insert(part, value);
+            }
+            days.insert(day, expected);
+        }
+
+        Ok(Self { days })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day)?.get(&part).map(|s| s.as_str())
+    }
+}
+
+fn number_from_key(key: &str, prefix: &str) -> Result<u8, String> {
+    key.strip_prefix(prefix)
+        .and_then(|n| n.parse().ok())
+        .ok_or(format!("invalid key '{}', expected {}<N>", key, prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answers;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(concat!(
+            "[day5]\n",
+            "part1 = 35\n",
+            "part2 = \"46\"\n",
+            "\n",
+            "[day9]\n",
+            "part2 = -2\n",
+        ))
+        .unwrap();
+        assert_eq!(answers.get(5, 1), Some("35"));
+        assert_eq!(answers.get(5, 2), Some("46"));
+        assert_eq!(answers.get(9, 1), None);
+        assert_eq!(answers.get(9, 2), Some("-2"));
+        assert_eq!(answers.get(10, 1), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_key() {
+        assert!(Answers::parse("[five]\npart1 = 35\n").is_err());
+        assert!(Answers::parse("[day5]\nfirst = 35\n").is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use std::{path::Path, time::Duration};
+
+use aoc_common::{input::read_input, solution::Report};
+
+use crate::days::{self, Day};
+
+fn time_runs(day: &Day, input: &str, iterations: u32) -> Result<Report, String> {
+    let mut total = (day.runner)(input, &[1, 2])?;
+    for _ in 1..iterations {
+        let report = (day.runner)(input, &[1, 2])?;
+        total.parse_time += report.parse_time;
+        for (sum, part) in total.parts.iter_mut().zip(report.parts) {
+            sum.time += part.time;
+        }
+    }
+
+    total.parse_time /= iterations;
+    for part in total.parts.iter_mut() {
+        part.time /= iterations;
+    }
+    Ok(total)
+}
+
+fn cell(time: Duration) -> String {
+    format!("{:>12}", format!("{:.2?}", time))
+}
+
+pub fn bench(day: Option<u8>, inputs: &Path, iterations: u32) -> Result<(), String> {
+    let selected: Vec<&Day> = match day {
+        Some(day) => vec![days::find(day).ok_or(format!("no solution for day {}", day))?],
+        None => days::all().collect(),
+    };
+
+    println!(
+        "{:>3}  {:<8}{:>12}{:>12}{:>12}",
+        "day", "input", "parse", "part 1", "part 2"
+    );
+    for day in selected {
+        let path = inputs.join(format!("day{}.txt", day.number));
+        let mut runs = vec![("example", day.example.to_string())];
+        if let Ok(input) = read_input(&path) {
+            runs.push(("real", input));
+        }
+
+        for (label, input) in runs {
+            print!("{:>3}  {:<8}", day.number, label);
+            match time_runs(day, &input, iterations) {
+                Ok(report) => {
+                    print!("{}", cell(report.parse_time));
+                    for part in report.parts {
+                        match part.answer {
+                            Ok(_) => print!("{}", cell(part.time)),
+                            Err(_) => print!("{:>12}", "error"),
+                        }
+                    }
+                    println!();
+                }
+                Err(e) => println!("  error: {}", e),
+            }
+        }
+    }
+
+    Ok(())
+}
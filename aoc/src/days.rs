@@ -0,0 +1,103 @@
+use aoc_common::solution::{run, Runner};
+
+pub struct Day {
+    pub number: u8,
+    pub runner: Runner,
+    pub example: &'static str,
+}
+
+const DAYS: &[Day] = &[
+    Day {
+        number: 2,
+        runner: run::<day2::Day2>,
+        example: day2::EXAMPLE,
+    },
+    Day {
+        number: 4,
+        runner: run::<day4::Day4>,
+        example: day4::EXAMPLE,
+    },
+    Day {
+        number: 5,
+        runner: run::<day5::Day5>,
+        example: day5::EXAMPLE,
+    },
+    Day {
+        number: 6,
+        runner: run::<day6::Day6>,
+        example: day6::EXAMPLE,
+    },
+    Day {
+        number: 7,
+        runner: run::<day7::Day7>,
+        example: day7::EXAMPLE,
+    },
+    Day {
+        number: 8,
+        runner: run::<day8::Day8>,
+        example: day8::EXAMPLE,
+    },
+    Day {
+        number: 9,
+        runner: run::<day9::Day9>,
+        example: day9::EXAMPLE,
+    },
+    Day {
+        number: 10,
+        runner: run::<day10::Day10>,
+        example: day10::EXAMPLE,
+    },
+    Day {
+        number: 11,
+        runner: run::<day11::Day11>,
+        example: day11::EXAMPLE,
+    },
+    Day {
+        number: 12,
+        runner: run::<day12::Day12>,
+        example: day12::EXAMPLE,
+    },
+    Day {
+        number: 13,
+        runner: run::<day13::Day13>,
+        example: day13::EXAMPLE,
+    },
+    Day {
+        number: 14,
+        runner: run::<day14::Day14>,
+        example: day14::EXAMPLE,
+    },
+    Day {
+        number: 15,
+        runner: run::<day15::Day15>,
+        example: day15::EXAMPLE,
+    },
+    Day {
+        number: 16,
+        runner: run::<day16::Day16>,
+        example: day16::EXAMPLE,
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == day)
+}
+
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    DAYS.iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::all;
+
+    #[test]
+    fn test_examples_solve() {
+        for day in all() {
+            let report = (day.runner)(day.example, &[1, 2]).unwrap();
+            for part in report.parts {
+                assert!(part.answer.is_ok(), "day {}: {:?}", day.number, part);
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+mod answers;
+mod bench;
+mod days;
+mod verify;
+
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::{
+    output::{disable_colour_unless_terminal, Format},
+    solution::solve_files,
+};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day
+    Run {
+        day: u8,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input files, - reads stdin, defaults to inputs/day<DAY>.txt
+        #[arg(long, num_args = 1..)]
+        input: Vec<PathBuf>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Run every day on its input and compare against the known answers
+    Verify {
+        /// File with the expected answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Directory containing day<N>.txt for every day
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+    },
+    /// Time parsing and both parts on the example and the real input
+    Bench {
+        /// Only benchmark this day
+        day: Option<u8>,
+        /// Directory containing day<N>.txt for every day
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+        /// Number of runs to average over
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+    },
+}
+
+fn run(day: u8, part: Option<u8>, mut inputs: Vec<PathBuf>, format: Format) -> Result<(), String> {
+    let runner = days::find(day)
+        .ok_or(format!("no solution for day {}", day))?
+        .runner;
+    if inputs.is_empty() {
+        inputs.push(PathBuf::from(format!("inputs/day{}.txt", day)));
+    }
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    if !solve_files(day, runner, &inputs, &parts, format) {
+        return Err(format!("day {} failed", day));
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    disable_colour_unless_terminal();
+
+    let res = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input, format),
+        Command::Verify { answers, inputs } => verify::verify(&answers, &inputs),
+        Command::Bench {
+            day,
+            inputs,
+            iterations,
+        } => bench::bench(day, &inputs, iterations),
+    };
+
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
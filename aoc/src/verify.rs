@@ -0,0 +1,107 @@
+use std::path::Path;
+
+use aoc_common::{input::read_input, solution::PartResult};
+use colored::Colorize;
+
+use crate::{
+    answers::Answers,
+    days::{self, Day},
+};
+
+enum Status {
+    Pass,
+    Fail(String),
+    Missing(String),
+}
+
+struct Check {
+    day: u8,
+    part: u8,
+    status: Status,
+}
+
+fn check_day(day: &Day, answers: &Answers, inputs: &Path) -> Vec<Check> {
+    let Day {
+        number: day,
+        runner,
+        ..
+    } = *day;
+    let missing = |reason: String| {
+        [1, 2]
+            .into_iter()
+            .map(|part| Check {
+                day,
+                part,
+                status: Status::Missing(reason.clone()),
+            })
+            .collect()
+    };
+
+    let path = inputs.join(format!("day{}.txt", day));
+    let input = match read_input(&path) {
+        Ok(input) => input,
+        Err(_) => return missing(format!("no input {}", path.display())),
+    };
+
+    let report = match runner(&input, &[1, 2]) {
+        Ok(report) => report,
+        Err(e) => {
+            return [1, 2]
+                .into_iter()
+                .map(|part| Check {
+                    day,
+                    part,
+                    status: Status::Fail(e.lines().next().unwrap_or_default().to_string()),
+                })
+                .collect()
+        }
+    };
+
+    report
+        .parts
+        .into_iter()
+        .map(|PartResult { part, answer, .. }| {
+            let status = match (answers.get(day, part), answer) {
+                (None, _) => Status::Missing("no expected answer".to_string()),
+                (Some(_), Err(e)) => Status::Fail(e),
+                (Some(expected), Ok(answer)) if answer.to_string() == expected => Status::Pass,
+                (Some(expected), Ok(answer)) => {
+                    Status::Fail(format!("expected {}, got {}", expected, answer))
+                }
+            };
+            Check { day, part, status }
+        })
+        .collect()
+}
+
+pub fn verify(answers: &Path, inputs: &Path) -> Result<(), String> {
+    let answers = Answers::load(answers)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days::all() {
+        for check in check_day(day, &answers, inputs) {
+            let label = format!("Day {} part {}", check.day, check.part);
+            match check.status {
+                Status::Pass => {
+                    passed += 1;
+                    println!("{}: {}", label, "pass".green());
+                }
+                Status::Fail(reason) => {
+                    failed += 1;
+                    println!("{}: {} ({})", label, "FAIL".red(), reason);
+                }
+                Status::Missing(reason) => {
+                    missing += 1;
+                    println!("{}: {} ({})", label, "missing".yellow(), reason);
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        return Err(format!("{} answers differ from the expected ones", failed));
+    }
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 #!/bin/env sh
+cargo clean
 for dir in day*; do
-        cd $dir &&
-        ./clean &&
-        cd ..
+        if [ ! -f $dir/Cargo.toml ]; then
+                cd $dir &&
+                ./clean &&
+                cd ..
+        fi
 done
@@ -0,0 +1,412 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+};
+
+use aoc_common::{
+    geom::{Coord, Direction},
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use colored::Colorize;
+
+#[derive(Eq)]
+struct Tile {
+    dir1: Option<Direction>,
+    dir2: Option<Direction>,
+    print: char,
+}
+
+#[derive(PartialEq, Eq)]
+enum Place {
+    Inside,
+    Outside,
+    Loop { crossing: bool },
+}
+
+pub struct Map {
+    tiles: Grid<char>,
+}
+
+#[derive(Debug)]
+pub enum MapError {
+    Parse(ParseError),
+    NoStart,
+    NoLoop,
+}
+
+pub struct Day10;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Map;
+    type Error = MapError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let tiles = Grid::parse(input, "a pipe, '.' or 'S'", |c| {
+            Tile::try_from(c).is_ok().then_some(c)
+        })?;
+        let map = Map { tiles };
+        map.find_start().ok_or(MapError::NoStart)?;
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part1(map)?.into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part2(map)?.into())
+    }
+}
+
+impl Display for MapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapError::Parse(e) => Display::fmt(e, f),
+            MapError::NoStart => f.write_str("no start tile"),
+            MapError::NoLoop => f.write_str("the start tile is not on a loop of pipes"),
+        }
+    }
+}
+
+impl From<ParseError> for MapError {
+    fn from(value: ParseError) -> Self {
+        MapError::Parse(value)
+    }
+}
+
+fn part1(map: &Map) -> Result<usize, MapError> {
+    Ok(map.find_loop()?.len() / 2)
+}
+
+fn part2(map: &Map) -> Result<usize, MapError> {
+    map.count_inside_loop()
+}
+
+impl Tile {
+    fn can_go(&self, dir: Direction) -> bool {
+        self.go(dir).is_some()
+    }
+
+    fn go(&self, from: Direction) -> Option<Direction> {
+        if Some(from) == self.dir1 {
+            Some(self.dir2?)
+        } else if Some(from) == self.dir2 {
+            Some(self.dir1?)
+        } else {
+            None
+        }
+    }
+}
+
+impl PartialEq for Tile {
+    fn eq(&self, other: &Self) -> bool {
+        (self.dir1 == other.dir1 && self.dir2 == other.dir2)
+            || (self.dir1 == other.dir2 && self.dir2 == other.dir1)
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        use Direction::*;
+        let tile = match value {
+            '|' => Self {
+                dir1: Some(North),
+                dir2: Some(South),
+                print: '║',
+            },
+            '-' => Self {
+                dir1: Some(West),
+                dir2: Some(East),
+                print: '═',
+            },
+            'L' => Self {
+                dir1: Some(North),
+                dir2: Some(East),
+                print: '╚',
+            },
+            'J' => Self {
+                dir1: Some(North),
+                dir2: Some(West),
+                print: '╝',
+            },
+            '7' => Self {
+                dir1: Some(South),
+                dir2: Some(West),
+                print: '╗',
+            },
+            'F' => Self {
+                dir1: Some(South),
+                dir2: Some(East),
+                print: '╔',
+            },
+            '.' => Self {
+                dir1: None,
+                dir2: None,
+                print: '░',
+            },
+            'S' => Self {
+                dir1: None,
+                dir2: None,
+                print: '*',
+            },
+            _ => return Err(value),
+        };
+        Ok(tile)
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.print)
+    }
+}
+
+impl Map {
+    fn find_loop(&self) -> Result<HashSet<Coord>, MapError> {
+        let mut dirs = HashSet::new();
+
+        let start = self.find_start().ok_or(MapError::NoStart)?;
+        let mut pos = start;
+
+        let [mut dir, _] = self.directions_from_start()[..] else {
+            return Err(MapError::NoLoop);
+        };
+
+        loop {
+            dirs.insert(pos);
+            /* apply dir */
+            pos = pos.step(dir, self.tiles.bounds()).ok_or(MapError::NoLoop)?;
+
+            if pos == start {
+                break;
+            }
+
+            /* new dir */
+            dir = self
+                .tile_at(pos)
+                .and_then(|tile| tile.go(dir.reverse()))
+                .ok_or(MapError::NoLoop)?;
+        }
+
+        Ok(dirs)
+    }
+
+    fn classify(&self) -> Result<Vec<Vec<(Tile, Place)>>, MapError> {
+        let found_loop = self.find_loop()?;
+        // a loop leaves the start tile in exactly two directions
+        let start_dirs = self.directions_from_start();
+        let mut rows = vec![];
+
+        for (y, line) in self.tiles.rows().enumerate() {
+            let mut loop_intersect_count = 0;
+            let mut row = vec![];
+            for (x, &char) in line.iter().enumerate() {
+                let on_loop = found_loop.contains(&Coord { row: y, col: x });
+                let mut tile = Tile::try_from(char).expect("Unknown tile in map");
+
+                if char == 'S' {
+                    tile = Tile {
+                        dir1: Some(start_dirs[0]),
+                        dir2: Some(start_dirs[1]),
+                        print: tile.print,
+                    }
+                }
+
+                let place = if on_loop {
+                    let crossing = tile.can_go(Direction::North);
+                    if crossing {
+                        loop_intersect_count += 1;
+                    }
+                    Place::Loop { crossing }
+                } else if loop_intersect_count % 2 == 1 {
+                    Place::Inside
+                } else {
+                    Place::Outside
+                };
+
+                row.push((tile, place));
+            }
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
+
+    fn count_inside_loop(&self) -> Result<usize, MapError> {
+        Ok(self
+            .classify()?
+            .iter()
+            .flatten()
+            .filter(|(_, place)| *place == Place::Inside)
+            .count())
+    }
+
+    fn tile_at(&self, c: Coord) -> Option<Tile> {
+        Tile::try_from(*self.tiles.get(c)?).ok()
+    }
+
+    fn find_start(&self) -> Option<Coord> {
+        self.tiles.position(|c| *c == 'S')
+    }
+
+    fn directions_from_start(&self) -> Vec<Direction> {
+        let Some(start) = self.find_start() else {
+            return vec![];
+        };
+
+        Direction::ALL
+            .into_iter()
+            .filter(|dir| {
+                start
+                    .step(*dir, self.tiles.bounds())
+                    .and_then(|c| self.tile_at(c))
+                    .is_some_and(|t| t.can_go(dir.reverse()))
+            })
+            .collect()
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Ok(rows) = self.classify() else {
+            // without a loop there is nothing to colour
+            return write!(f, "{}", self.tiles);
+        };
+        for row in rows {
+            for (tile, place) in row {
+                let tile = tile.to_string();
+                let tile = match place {
+                    Place::Inside => tile.green(),
+                    Place::Loop { crossing: true } => tile.blue().underline(),
+                    Place::Loop { crossing: false } => tile.blue(),
+                    Place::Outside => tile.red(),
+                };
+                write!(f, "{}", tile)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::solution::Solution;
+
+    use crate::{part1, part2, Day10, Map, MapError};
+
+    fn map(lines: &[&str]) -> Map {
+        Day10::parse(&lines.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn test_part1_1() {
+        let map = map(&[".....", ".S-7.", ".|.|.", ".L-J.", "....."]);
+        assert_eq!(part1(&map).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_part1_2() {
+        let map = map(&["..F7.", ".FJ|.", "SJ.L7", "|F--J", "LJ..."]);
+        assert_eq!(part1(&map).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_part2_1() {
+        let map = map(&[
+            "...........",
+            ".S-------7.",
+            ".|F-----7|.",
+            ".||.....||.",
+            ".||.....||.",
+            ".|L-7.F-J|.",
+            ".|..|.|..|.",
+            ".L--J.L--J.",
+            "...........",
+        ]);
+        assert_eq!(part2(&map).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_part2_2() {
+        let map = map(&[
+            "..........",
+            ".S------7.",
+            ".|F----7|.",
+            ".||....||.",
+            ".||....||.",
+            ".|L-7F-J|.",
+            ".|..||..|.",
+            ".L--JL--J.",
+            "..........",
+        ]);
+        assert_eq!(part2(&map).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_part2_3() {
+        let map = map(&[
+            ".F----7F7F7F7F-7....",
+            ".|F--7||||||||FJ....",
+            ".||.FJ||||||||L7....",
+            "FJL7L7LJLJ||LJ.L-7..",
+            "L--J.L7...LJS7F-7L7.",
+            "....F-J..F7FJ|L7L7L7",
+            "....L7.F7||L7|.L7L7|",
+            ".....|FJLJ|FJ|F7|.LJ",
+            "....FJL-7.||.||||...",
+            "....L---J.LJ.LJLJ...",
+        ]);
+        assert_eq!(part2(&map).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_part2_4() {
+        let map = map(&[
+            "FF7FSF7F7F7F7F7F---7",
+            "L|LJ||||||||||||F--J",
+            "FL-7LJLJ||||||LJL-77",
+            "F--JF--7||LJLJ7F7FJ-",
+            "L---JF-JLJ.||-FJLJJ7",
+            "|F|F-JF---7F7-L7L|7|",
+            "|FFJF7L7F-JF7|JL---7",
+            "7-L-JL7||F7|L7F-7F7|",
+            "L.L7LFJ|||||FJL7||LJ",
+            "L7JLJL-JLJLJL--JLJ.L",
+        ]);
+        assert_eq!(part2(&map).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_start_connects_east() {
+        let map = map(&["S-7", "|.|", "L-J"]);
+        assert_eq!(part1(&map).unwrap(), 4);
+        assert_eq!(part2(&map).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_no_loop() {
+        // no pipes from the start, and pipes that lead off into the ground
+        for lines in [&["S"][..], &["S-7", "|.|", "L-."]] {
+            let map = map(lines);
+            assert!(matches!(Day10::part1(&map), Err(MapError::NoLoop)));
+            assert!(matches!(Day10::part2(&map), Err(MapError::NoLoop)));
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = match Day10::parse(".....\n.S-7.\n.|x|.").err().unwrap() {
+            MapError::Parse(e) => e,
+            e => panic!("unexpected error {}", e),
+        };
+        assert_eq!((e.line, e.column, e.found.as_str()), (3, 3, "x"));
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    collections::HashSet,
+    hash::{Hash, Hasher},
+};
+
+use aoc_common::{
+    geom::Coord,
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct Universe {
+    galaxies: HashSet<Galaxy>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Galaxy {
+    number: usize,
+    coord: Coord,
+}
+
+pub struct Day11;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Universe;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Universe::new(input)
+    }
+
+    fn part1(u: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(solve(&u.expanded(2)).into())
+    }
+
+    fn part2(u: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(solve(&u.expanded(1_000_000)).into())
+    }
+}
+
+fn solve(u: &Universe) -> usize {
+    u.galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(c1, c2)| c1.coord.manhattan(&c2.coord))
+        .sum()
+}
+
+impl Universe {
+    fn new(input: &str) -> Result<Universe, ParseError> {
+        let image = Grid::parse(input, "'.' or '#'", |c| matches!(c, '.' | '#').then_some(c))?;
+        let galaxies = image
+            .iter()
+            .filter(|(_, c)| **c == '#')
+            .enumerate()
+            .map(|(i, (coord, _))| Galaxy {
+                number: i + 1,
+                coord,
+            })
+            .collect();
+
+        Ok(Self {
+            galaxies,
+            width: image.width(),
+            height: image.height(),
+        })
+    }
+
+    fn expanded(&self, replace_empty_with: usize) -> Universe {
+        let empty_rows: Vec<_> = (0..self.height())
+            .filter(|r| self.is_row_empty(*r))
+            .collect();
+        let empty_cols: Vec<_> = (0..self.width())
+            .filter(|c| self.is_col_empty(*c))
+            .collect();
+
+        fn count_empty_rows_below(empty_rows: &[usize], row: usize) -> usize {
+            empty_rows.iter().filter(|r| **r < row).count()
+        }
+
+        fn count_empty_cols_before(empty_cols: &[usize], col: usize) -> usize {
+            empty_cols.iter().filter(|c| **c < col).count()
+        }
+
+        fn get_with_replacement(old: usize, count: usize, replacement: usize) -> usize {
+            (old - count) + (count * replacement)
+        }
+
+        let galaxies = self
+            .galaxies
+            .iter()
+            .map(|g| {
+                let empty_rows = count_empty_rows_below(&empty_rows, g.coord.row);
+                let empty_cols = count_empty_cols_before(&empty_cols, g.coord.col);
+
+                let row = get_with_replacement(g.coord.row, empty_rows, replace_empty_with);
+                let col = get_with_replacement(g.coord.col, empty_cols, replace_empty_with);
+
+                Galaxy {
+                    number: g.number,
+                    coord: Coord { row, col },
+                }
+            })
+            .collect();
+
+        Self {
+            galaxies,
+            width: get_with_replacement(self.width(), empty_cols.len(), replace_empty_with),
+            height: get_with_replacement(self.height(), empty_rows.len(), replace_empty_with),
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn is_row_empty(&self, row: usize) -> bool {
+        for g in self.galaxies.iter() {
+            if g.coord.row == row {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn is_col_empty(&self, col: usize) -> bool {
+        for g in self.galaxies.iter() {
+            if g.coord.col == col {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl Hash for Galaxy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coord.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::geom::Coord;
+
+    use aoc_common::solution::Solution;
+
+    use crate::{solve, Day11, Galaxy, Universe};
+
+    #[test]
+    fn test_expand_1() {
+        let input = concat!(
+            "...#......\n",
+            ".......#..\n",
+            "#.........\n",
+            "..........\n",
+            "......#...\n",
+            ".#........\n",
+            ".........#\n",
+            "..........\n",
+            ".......#..\n",
+            "#...#.....\n",
+        );
+        let u = Universe::new(input).unwrap();
+        assert_eq!(u.width(), 10);
+        assert_eq!(u.height(), 10);
+        let u = u.expanded(2);
+        assert_eq!(u.width(), 13);
+        assert_eq!(u.height(), 12);
+    }
+
+    #[test]
+    fn test_expand_10() {
+        let input = concat!(
+            "...#......\n",
+            ".......#..\n",
+            "#.........\n",
+            "..........\n",
+            "......#...\n",
+            ".#........\n",
+            ".........#\n",
+            "..........\n",
+            ".......#..\n",
+            "#...#.....\n",
+        );
+        let u = Universe::new(input).unwrap();
+        assert_eq!(u.width(), 10);
+        assert_eq!(u.height(), 10);
+        let u = u.expanded(10);
+        assert_eq!(u.width(), 37);
+        assert_eq!(u.height(), 28);
+    }
+
+    #[test]
+    fn test_galaxies() {
+        let input = concat!("...#.\n", ".#...\n", "#....\n",);
+        let u = Universe::new(input).unwrap();
+        assert_eq!(
+            u.galaxies,
+            HashSet::from_iter(
+                [
+                    Galaxy {
+                        coord: Coord { row: 0, col: 3 },
+                        number: 1
+                    },
+                    Galaxy {
+                        coord: Coord { row: 1, col: 1 },
+                        number: 2
+                    },
+                    Galaxy {
+                        coord: Coord { row: 2, col: 0 },
+                        number: 3
+                    }
+                ]
+                .iter()
+                .cloned()
+            )
+        )
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = concat!(
+            "...#......\n",
+            ".......#..\n",
+            "#.........\n",
+            "..........\n",
+            "......#...\n",
+            ".#........\n",
+            ".........#\n",
+            "..........\n",
+            ".......#..\n",
+            "#...#.....\n",
+        );
+        let u = Universe::new(input).unwrap();
+        assert_eq!(solve(&u.expanded(2)), 374);
+    }
+
+    #[test]
+    fn test_part2_1() {
+        let input = concat!(
+            "...#......\n",
+            ".......#..\n",
+            "#.........\n",
+            "..........\n",
+            "......#...\n",
+            ".#........\n",
+            ".........#\n",
+            "..........\n",
+            ".......#..\n",
+            "#...#.....\n",
+        );
+        let u = Universe::new(input).unwrap();
+        assert_eq!(solve(&u.expanded(10)), 1030);
+    }
+
+    #[test]
+    fn test_part2_2() {
+        let input = concat!(
+            "...#......\n",
+            ".......#..\n",
+            "#.........\n",
+            "..........\n",
+            "......#...\n",
+            ".#........\n",
+            ".........#\n",
+            "..........\n",
+            ".......#..\n",
+            "#...#.....\n",
+        );
+        let u = Universe::new(input).unwrap();
+        assert_eq!(solve(&u.expanded(100)), 8410);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day11::parse("...#\n.#.\n").err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (2, 1, "a line of 4 characters")
+        );
+
+        let e = Day11::parse("...#\n.#*.\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 3, "*"));
+    }
+}
@@ -0,0 +1,350 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use aoc_common::{
+    parse::{number, split_once, ParseError},
+    solution::{Answer, Solution},
+};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Condition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(PartialEq, Eq)]
+pub struct Row {
+    springs: Vec<Condition>,
+    damaged: Vec<usize>,
+}
+
+pub struct Day12;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .map(|l| Row::from_str(l).map_err(|e| e.within(input, l)))
+            .collect()
+    }
+
+    fn part1(rows: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(solve(rows).into())
+    }
+
+    fn part2(rows: &Self::Input) -> Result<Answer, Self::Error> {
+        let unfolded: Vec<_> = rows.iter().map(|r| r.unfolded()).collect();
+        Ok(solve(&unfolded).into())
+    }
+}
+
+fn solve(rows: &[Row]) -> usize {
+    rows.iter().map(|r| r.arrangements()).sum()
+}
+
+impl Row {
+    fn arrangements(&self) -> usize {
+        let mut cache = HashMap::new();
+
+        fn can_fit_damaged_streak(springs: &[Condition], count: usize) -> bool {
+            // Very much inspired by to https://github.com/Sp00ph
+            let mut springs = springs;
+            for _ in 0..count {
+                if let [Condition::Unknown | Condition::Damaged, rest @ ..] = springs {
+                    springs = rest;
+                } else {
+                    return false;
+                }
+            }
+
+            springs.first() != Some(&Condition::Damaged)
+        }
+
+        fn insert_cache(
+            cache: &mut HashMap<(usize, usize), usize>,
+            key: (usize, usize),
+            val: usize,
+        ) -> usize {
+            cache.insert(key, val);
+            val
+        }
+
+        fn count(
+            springs: &[Condition],
+            damaged: &[usize],
+            cache: &mut HashMap<(usize, usize), usize>,
+        ) -> usize {
+            let key = (springs.len(), damaged.len());
+
+            if let Some(val) = cache.get(&key) {
+                return *val;
+            }
+
+            if springs.is_empty() {
+                return insert_cache(cache, key, usize::from(damaged.is_empty()));
+            }
+
+            if damaged.is_empty() {
+                let res = usize::from(!springs.iter().any(|c| matches!(c, Condition::Damaged)));
+                return insert_cache(cache, key, res);
+            }
+
+            match springs[0] {
+                Condition::Operational => {
+                    let res = count(&springs[1..], damaged, cache);
+                    insert_cache(cache, key, res)
+                }
+                Condition::Damaged => {
+                    if can_fit_damaged_streak(springs, damaged[0]) {
+                        let res = count(
+                            springs.get(damaged[0] + 1..).unwrap_or(&[]),
+                            &damaged[1..],
+                            cache,
+                        );
+                        insert_cache(cache, key, res)
+                    } else {
+                        insert_cache(cache, key, 0)
+                    }
+                }
+                Condition::Unknown => {
+                    let operational_count = count(&springs[1..], damaged, cache);
+                    let mut damaged_count = 0;
+                    if can_fit_damaged_streak(springs, damaged[0]) {
+                        damaged_count = count(
+                            springs.get(damaged[0] + 1..).unwrap_or(&[]),
+                            &damaged[1..],
+                            cache,
+                        );
+                    }
+                    let res = operational_count + damaged_count;
+                    insert_cache(cache, key, res)
+                }
+            }
+        }
+
+        count(&self.springs, &self.damaged, &mut cache)
+    }
+
+    fn unfolded(&self) -> Self {
+        let mut springs = Vec::with_capacity(self.springs.len() * 5);
+
+        for _ in 0..5 {
+            springs.append(&mut self.springs.to_vec());
+            springs.push(Condition::Unknown);
+        }
+        springs.pop();
+
+        Self {
+            springs,
+            damaged: std::iter::repeat_n(self.damaged.iter(), 5)
+                .flatten()
+                .copied()
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<char> for Condition {
+    type Error = char;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(value),
+        }
+    }
+}
+
+impl FromStr for Row {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (springs, damaged) = split_once(s, s, ' ')?;
+        let springs = springs
+            .char_indices()
+            .map(|(i, c)| {
+                Condition::try_from(c).map_err(|c| {
+                    ParseError::new(s, &springs[i..i + c.len_utf8()], "'.', '#' or '?'")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let damaged = damaged
+            .split(',')
+            .map(|n| number(s, n))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { springs, damaged })
+    }
+}
+
+impl Display for Row {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            &self
+                .springs
+                .iter()
+                .map(|c| match c {
+                    Condition::Operational => '.',
+                    Condition::Damaged => '#',
+                    Condition::Unknown => '?',
+                })
+                .join(""),
+        )?;
+        f.write_char(' ')?;
+        f.write_str(&self.damaged.iter().join(","))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use aoc_common::solution::Solution;
+
+    use crate::{solve, Day12, Row};
+
+    #[test]
+    fn test_part1_line1() {
+        let input = "???.### 1,1,3";
+        assert_eq!(Row::from_str(input).unwrap().arrangements(), 1);
+    }
+
+    #[test]
+    fn test_part1_line2() {
+        let input = ".??..??...?##. 1,1,3";
+        assert_eq!(Row::from_str(input).unwrap().arrangements(), 4);
+    }
+
+    #[test]
+    fn test_part1_line3() {
+        let input = "?#?#?#?#?#?#?#? 1,3,1,6";
+        assert_eq!(Row::from_str(input).unwrap().arrangements(), 1);
+    }
+
+    #[test]
+    fn test_part1_line4() {
+        let input = "????.#...#... 4,1,1";
+        assert_eq!(Row::from_str(input).unwrap().arrangements(), 1);
+    }
+
+    #[test]
+    fn test_part1_line5() {
+        let input = "????.######..#####. 1,6,5";
+        assert_eq!(Row::from_str(input).unwrap().arrangements(), 4);
+    }
+
+    #[test]
+    fn test_part1_line6() {
+        let input = "?###???????? 3,2,1";
+        assert_eq!(Row::from_str(input).unwrap().arrangements(), 10);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = concat!(
+            "???.### 1,1,3\n",
+            ".??..??...?##. 1,1,3\n",
+            "?#?#?#?#?#?#?#? 1,3,1,6\n",
+            "????.#...#... 4,1,1\n",
+            "????.######..#####. 1,6,5\n",
+            "?###???????? 3,2,1\n",
+        );
+        let rows: Vec<Row> = input.lines().map(|l| Row::from_str(l).unwrap()).collect();
+        assert_eq!(solve(&rows), 21);
+    }
+
+    #[test]
+    fn test_unfolded() {
+        let input = ".# 1";
+        let row = Row::from_str(input).unwrap();
+        let unfolded = row.unfolded();
+
+        assert_eq!(row.to_string(), ".# 1");
+        assert_eq!(unfolded.to_string(), ".#?.#?.#?.#?.# 1,1,1,1,1");
+    }
+
+    #[test]
+    fn test_part2_line1() {
+        let input = "???.### 1,1,3";
+        assert_eq!(Row::from_str(input).unwrap().unfolded().arrangements(), 1);
+    }
+
+    #[test]
+    fn test_part2_line2() {
+        let input = ".??..??...?##. 1,1,3";
+        assert_eq!(
+            Row::from_str(input).unwrap().unfolded().arrangements(),
+            16384
+        );
+    }
+
+    #[test]
+    fn test_part2_line3() {
+        let input = "?#?#?#?#?#?#?#? 1,3,1,6";
+        assert_eq!(Row::from_str(input).unwrap().unfolded().arrangements(), 1);
+    }
+
+    #[test]
+    fn test_part2_line4() {
+        let input = "????.#...#... 4,1,1";
+        assert_eq!(Row::from_str(input).unwrap().unfolded().arrangements(), 16);
+    }
+
+    #[test]
+    fn test_part2_line5() {
+        let input = "????.######..#####. 1,6,5";
+        assert_eq!(
+            Row::from_str(input).unwrap().unfolded().arrangements(),
+            2500
+        );
+    }
+
+    #[test]
+    fn test_part2_line6() {
+        let input = "?###???????? 3,2,1";
+        assert_eq!(
+            Row::from_str(input).unwrap().unfolded().arrangements(),
+            506250
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = concat!(
+            "???.### 1,1,3\n",
+            ".??..??...?##. 1,1,3\n",
+            "?#?#?#?#?#?#?#? 1,3,1,6\n",
+            "????.#...#... 4,1,1\n",
+            "????.######..#####. 1,6,5\n",
+            "?###???????? 3,2,1\n",
+        );
+        let rows: Vec<Row> = input
+            .lines()
+            .map(|l| Row::from_str(l).unwrap().unfolded())
+            .collect();
+        assert_eq!(solve(&rows), 525152);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day12::parse("???.### 1,1,3\n.??..x?...?##. 1,1,3")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 6, "x"));
+
+        let e = Day12::parse("???.### 1,,3").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 11, ""));
+    }
+}
@@ -0,0 +1,296 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_common::{
+    grid::Grid,
+    input::blocks,
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
+pub struct Pattern {
+    grid: Grid<char>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+enum Reflection {
+    Horizontal { rows: usize },
+    Vertical { cols: usize },
+}
+
+#[derive(Debug)]
+pub enum PatternError {
+    Parse(ParseError),
+    NoReflection,
+}
+
+pub struct Day13;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Pattern>;
+    type Error = PatternError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        blocks(input)
+            .map(|block| Pattern::from_str(block).map_err(|e| e.within(input, block).into()))
+            .collect()
+    }
+
+    fn part1(patterns: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part1(patterns)?.into())
+    }
+
+    fn part2(patterns: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part2(patterns)?.into())
+    }
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternError::Parse(e) => Display::fmt(e, f),
+            PatternError::NoReflection => f.write_str("pattern has no reflection"),
+        }
+    }
+}
+
+impl From<ParseError> for PatternError {
+    fn from(value: ParseError) -> Self {
+        PatternError::Parse(value)
+    }
+}
+
+fn part1(patterns: &[Pattern]) -> Result<usize, PatternError> {
+    patterns
+        .iter()
+        .map(|p| p.reflection_without_change())
+        .map(|r| Some(r?.value()))
+        .sum::<Option<usize>>()
+        .ok_or(PatternError::NoReflection)
+}
+
+fn part2(patterns: &[Pattern]) -> Result<usize, PatternError> {
+    patterns
+        .iter()
+        .map(|p| p.reflection_with_single_change())
+        .map(|r| Some(r?.value()))
+        .sum::<Option<usize>>()
+        .ok_or(PatternError::NoReflection)
+}
+
+fn difference_between_strs(s1: &str, s2: &str) -> usize {
+    assert!(s1.len() == s2.len());
+    s1.chars()
+        .zip(s2.chars())
+        .filter(|(c1, c2)| *c1 != *c2)
+        .count()
+}
+
+fn difference_between_str_list(v1: &[String], v2: &[String]) -> usize {
+    v1.iter()
+        .zip(v2.iter())
+        .map(|(s1, s2)| difference_between_strs(s1, s2))
+        .sum()
+}
+
+impl Reflection {
+    fn value(&self) -> usize {
+        match self {
+            Reflection::Horizontal { rows } => 100 * rows,
+            Reflection::Vertical { cols } => *cols,
+        }
+    }
+}
+
+impl Pattern {
+    fn reflection_without_change(&self) -> Option<Reflection> {
+        // horizontal
+        for r in 1..self.grid.height() {
+            let mut above: Vec<_> = self.rows().take(r).collect();
+            above.reverse();
+            let below: Vec<_> = self.rows().skip(r).collect();
+            if above.iter().zip(below.iter()).all(|(a, b)| a == b) {
+                return Some(Reflection::Horizontal { rows: r });
+            }
+        }
+
+        // vertical
+        for c in 1..self.grid.width() {
+            let mut left: Vec<_> = self.cols().take(c).collect();
+            left.reverse();
+            let right: Vec<_> = self.cols().skip(c).collect();
+            if left.iter().zip(right.iter()).all(|(l, r)| l == r) {
+                return Some(Reflection::Vertical { cols: c });
+            }
+        }
+
+        None
+    }
+
+    fn reflection_with_single_change(&self) -> Option<Reflection> {
+        // horizontal
+        for r in 1..self.grid.height() {
+            let mut above: Vec<_> = self.rows().take(r).collect();
+            above.reverse();
+            let below: Vec<_> = self.rows().skip(r).collect();
+
+            if difference_between_str_list(above.as_slice(), below.as_slice()) == 1 {
+                return Some(Reflection::Horizontal { rows: r });
+            }
+        }
+
+        // vertical
+        for c in 1..self.grid.width() {
+            let mut left: Vec<_> = self.cols().take(c).collect();
+            left.reverse();
+            let right: Vec<_> = self.cols().skip(c).collect();
+
+            if difference_between_str_list(left.as_slice(), right.as_slice()) == 1 {
+                return Some(Reflection::Vertical { cols: c });
+            }
+        }
+
+        None
+    }
+
+    fn rows(&self) -> impl Iterator<Item = String> + '_ {
+        self.grid.rows().map(|r| r.iter().collect())
+    }
+
+    fn cols(&self) -> impl Iterator<Item = String> + '_ {
+        self.grid.cols().map(|c| c.collect())
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(s, "'.' or '#'", |c| matches!(c, '.' | '#').then_some(c))?;
+        Ok(Self { grid })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use aoc_common::solution::Solution;
+
+    use crate::{part1, part2, Day13, Pattern, PatternError, Reflection};
+
+    #[test]
+    fn test_part1_p1() {
+        let input = [
+            "#.##..##.",
+            "..#.##.#.",
+            "##......#",
+            "##......#",
+            "..#.##.#.",
+            "..##..##.",
+            "#.#.##.#.",
+        ];
+        assert_eq!(
+            Pattern::from_str(&input.join("\n"))
+                .unwrap()
+                .reflection_without_change(),
+            Some(Reflection::Vertical { cols: 5 })
+        );
+    }
+
+    #[test]
+    fn test_part1_p2() {
+        let input = [
+            "#...##..#",
+            "#....#..#",
+            "..##..###",
+            "#####.##.",
+            "#####.##.",
+            "..##..###",
+            "#....#..#",
+        ];
+        assert_eq!(
+            Pattern::from_str(&input.join("\n"))
+                .unwrap()
+                .reflection_without_change(),
+            Some(Reflection::Horizontal { rows: 4 })
+        );
+    }
+
+    #[test]
+    fn test_part1_belowlonger() {
+        let input = [
+            "#...##..#",
+            "#....#..#",
+            "..##..###",
+            "#####.##.",
+            "#####.##.",
+            "..##..###",
+            "#....#..#",
+            "#...##..#",
+            "..#####..",
+        ];
+        assert_eq!(
+            Pattern::from_str(&input.join("\n"))
+                .unwrap()
+                .reflection_without_change(),
+            Some(Reflection::Horizontal { rows: 4 })
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = concat!(
+            "#.##..##.\n",
+            "..#.##.#.\n",
+            "##......#\n",
+            "##......#\n",
+            "..#.##.#.\n",
+            "..##..##.\n",
+            "#.#.##.#.\n",
+            "\n",
+            "#...##..#\n",
+            "#....#..#\n",
+            "..##..###\n",
+            "#####.##.\n",
+            "#####.##.\n",
+            "..##..###\n",
+            "#....#..#\n",
+        );
+        assert_eq!(part1(&Day13::parse(input).unwrap()).unwrap(), 405);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = concat!(
+            "#.##..##.\n",
+            "..#.##.#.\n",
+            "##......#\n",
+            "##......#\n",
+            "..#.##.#.\n",
+            "..##..##.\n",
+            "#.#.##.#.\n",
+            "\n",
+            "#...##..#\n",
+            "#....#..#\n",
+            "..##..###\n",
+            "#####.##.\n",
+            "#####.##.\n",
+            "..##..###\n",
+            "#....#..#\n",
+        );
+        assert_eq!(part2(&Day13::parse(input).unwrap()).unwrap(), 400);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "#.##\n..#.\n\n#...\n#..x\n";
+        let e = match Day13::parse(input).err().unwrap() {
+            PatternError::Parse(e) => e,
+            e => panic!("unexpected error {}", e),
+        };
+        assert_eq!((e.line, e.column, e.found.as_str()), (5, 4, "x"));
+    }
+}
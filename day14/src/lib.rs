@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    geom::Direction,
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
+#[derive(Clone)]
+pub struct Position {
+    grid: Grid<char>,
+}
+
+pub struct Day14;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Position;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Position::from_input(input)
+    }
+
+    fn part1(pos: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(pos.slide_north().load_north().into())
+    }
+
+    fn part2(pos: &Self::Input) -> Result<Answer, Self::Error> {
+        let mut pos = pos.clone();
+        pos.cycle(1_000_000_000);
+        Ok(pos.load_north().into())
+    }
+}
+
+impl Position {
+    fn from_input(input: &str) -> Result<Position, ParseError> {
+        let grid = Grid::parse(input, "'O', '#' or '.'", |c| {
+            matches!(c, 'O' | '#' | '.').then_some(c)
+        })?;
+        Ok(Self { grid })
+    }
+
+    fn slide_north(&self) -> Position {
+        let mut pos = self.clone();
+        pos.tilt(Direction::North);
+        pos
+    }
+
+    fn tilt(&mut self, dir: Direction) {
+        let mut coords: Vec<_> = self.grid.coords().collect();
+        if matches!(dir, Direction::South | Direction::East) {
+            coords.reverse();
+        }
+
+        let bounds = self.grid.bounds();
+        for start in coords {
+            if self.grid.get(start) != Some(&'O') {
+                continue;
+            }
+            let mut c = start;
+            while let Some(next) = c
+                .step(dir, bounds)
+                .filter(|n| self.grid.get(*n) == Some(&'.'))
+            {
+                c = next;
+            }
+            *self.grid.get_mut(start).unwrap() = '.';
+            *self.grid.get_mut(c).unwrap() = 'O';
+        }
+    }
+
+    fn do_cycle(&mut self) {
+        for dir in [
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ] {
+            self.tilt(dir);
+        }
+    }
+
+    fn cycle(&mut self, count: usize) {
+        let mut seen = HashMap::new();
+        for i in 1..=count {
+            self.do_cycle();
+
+            match seen.get(&self.grid) {
+                Some(o) => {
+                    let cycle = i - o;
+                    let rem = (count - i) % cycle;
+                    for _ in 0..rem {
+                        self.do_cycle();
+                    }
+                    break;
+                }
+                None => {
+                    seen.insert(self.grid.clone(), i);
+                }
+            };
+        }
+    }
+
+    fn load_north(&self) -> usize {
+        fn count_round_in_row(row: &[char]) -> usize {
+            row.iter().filter(|c| **c == 'O').count()
+        }
+
+        self.grid
+            .rows()
+            .map(count_round_in_row)
+            .enumerate()
+            .map(|(i, c)| (self.grid.height() - i) * c)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Position;
+
+    #[test]
+    fn test_part1() {
+        let input = concat!(
+            "O....#....\n",
+            "O.OO#....#\n",
+            ".....##...\n",
+            "OO.#O....O\n",
+            ".O.....O#.\n",
+            "O.#..O.#.#\n",
+            "..O..#O..O\n",
+            ".......O..\n",
+            "#....###..\n",
+            "#OO..#...."
+        );
+        let pos = Position::from_input(input).unwrap().slide_north();
+        assert_eq!(pos.load_north(), 136);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = concat!(
+            "O....#....\n",
+            "O.OO#....#\n",
+            ".....##...\n",
+            "OO.#O....O\n",
+            ".O.....O#.\n",
+            "O.#..O.#.#\n",
+            "..O..#O..O\n",
+            ".......O..\n",
+            "#....###..\n",
+            "#OO..#...."
+        );
+        let mut pos = Position::from_input(input).unwrap();
+        pos.cycle(1_000_000_000);
+        assert_eq!(pos.load_north(), 64);
+    }
+
+    #[test]
+    fn test_cycle_not_square() {
+        let mut pos = Position::from_input("O..\n...").unwrap();
+        pos.cycle(1);
+        assert_eq!(pos.grid.to_string(), "...\n..O");
+        assert_eq!(pos.load_north(), 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Position::from_input("O....\n.O#o.").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 4, "o"));
+    }
+}
@@ -0,0 +1,155 @@
+use aoc_common::{
+    parse::{number, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug)]
+pub struct Step {
+    label: String,
+    op: char,
+    focal: u64,
+}
+
+struct Lens {
+    label: String,
+    focal: u64,
+}
+
+pub struct InitSequence {
+    input: String,
+    steps: Vec<Step>,
+}
+
+pub struct Day15;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = InitSequence;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let steps = parse_steps(input)?;
+        let input = input.replace('\n', "");
+        Ok(InitSequence { input, steps })
+    }
+
+    fn part1(seq: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part1(&seq.input).into())
+    }
+
+    fn part2(seq: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part2(&seq.steps).into())
+    }
+}
+
+fn holliday_hash(input: &str) -> u8 {
+    let mut state: u32 = 0;
+    for b in input.as_bytes() {
+        state += *b as u32;
+        state *= 17;
+        state %= 256;
+    }
+    state as u8
+}
+
+fn parse_steps(input: &str) -> Result<Vec<Step>, ParseError> {
+    let mut insns = vec![];
+    for insn in input.trim_end().split(',') {
+        let end = insn
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(insn.len());
+        let (label, rest) = insn.split_at(end);
+        let (op, focal) = match rest.chars().next() {
+            Some('=') => ('=', number(input, &rest[1..])?),
+            Some('-') if rest.len() == 1 => ('-', 0),
+            Some('-') => return Err(ParseError::new(input, &rest[1..], "','")),
+            _ => return Err(ParseError::new(input, rest, "'-' or '='")),
+        };
+        insns.push(Step {
+            label: label.to_string(),
+            op,
+            focal,
+        });
+    }
+    Ok(insns)
+}
+
+fn part1(input: &str) -> u64 {
+    input.split(',').map(holliday_hash).map(|h| h as u64).sum()
+}
+
+fn part2(steps: &[Step]) -> u64 {
+    let mut map: Vec<Vec<Lens>> = Vec::with_capacity(256);
+    for _ in 0..256 {
+        map.push(vec![]);
+    }
+
+    for step in steps {
+        let idx = holliday_hash(&step.label);
+        let v = map.get_mut(idx as usize).unwrap();
+
+        let i = v.iter().position(|l| l.label == step.label);
+
+        match step.op {
+            '-' => {
+                if let Some(i) = i {
+                    v.remove(i);
+                }
+            }
+            '=' => {
+                if let Some(i) = i {
+                    v.get_mut(i).unwrap().focal = step.focal;
+                } else {
+                    v.push(Lens {
+                        label: step.label.clone(),
+                        focal: step.focal,
+                    });
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    let mut total: u64 = 0;
+    for (b, v) in map.iter().enumerate() {
+        for (s, l) in v.iter().enumerate() {
+            total += (b as u64 + 1) * (s as u64 + 1) * l.focal;
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{holliday_hash, parse_steps, part1, part2};
+
+    #[test]
+    fn test_hash() {
+        let input = "HASH";
+        assert_eq!(holliday_hash(input), 52);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(part1(input), 1320);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(part2(&parse_steps(input).unwrap()), 145);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_steps("rn=1,cm-,qp=x,cm=2").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 13, "x"));
+
+        let e = parse_steps("rn=1,cm+\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 8, "+"));
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    geom::{Coord, Direction},
+    grid::Grid,
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Beam {
+    coord: Coord,
+    dir: Direction,
+}
+
+pub struct Contraption {
+    field: Grid<char>,
+}
+
+pub struct Day16;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Contraption;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let field = Grid::parse(input, "a mirror, a splitter or '.'", |c| {
+            "./\\|-".contains(c).then_some(c)
+        })?;
+        Ok(Contraption { field })
+    }
+
+    fn part1(contraption: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part1(contraption).into())
+    }
+
+    fn part2(contraption: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part2(contraption).into())
+    }
+}
+
+fn outgoing(dir: Direction, tile: char) -> Vec<Direction> {
+    match tile {
+        '|' if dir.is_horizontal() => vec![Direction::North, Direction::South],
+        '-' if !dir.is_horizontal() => vec![Direction::West, Direction::East],
+        '/' if dir.is_horizontal() => vec![dir.turn_left()],
+        '/' => vec![dir.turn_right()],
+        '\\' if dir.is_horizontal() => vec![dir.turn_right()],
+        '\\' => vec![dir.turn_left()],
+        _ => vec![dir],
+    }
+}
+
+fn handle_beam(field: &Grid<char>, beam: &Beam, newbeams: &mut Vec<Beam>) {
+    let tile = *field.get(beam.coord).unwrap();
+    for dir in outgoing(beam.dir, tile) {
+        if let Some(coord) = beam.coord.step(dir, field.bounds()) {
+            newbeams.push(Beam { coord, dir });
+        }
+    }
+}
+
+fn simulate(field: &Grid<char>, initial_beam: Beam) -> usize {
+    let mut energized = HashSet::new();
+    let mut beams = vec![];
+    beams.push(initial_beam);
+
+    while !beams.is_empty() {
+        let mut newbeams = vec![];
+
+        for beam in &beams {
+            if !energized.contains(beam) {
+                energized.insert(*beam);
+                handle_beam(field, beam, &mut newbeams);
+            }
+        }
+
+        beams.clear();
+        beams.append(&mut newbeams);
+    }
+
+    energized
+        .iter()
+        .map(move |b| b.coord)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn part1(contraption: &Contraption) -> usize {
+    simulate(
+        &contraption.field,
+        Beam {
+            dir: Direction::East,
+            coord: Coord { row: 0, col: 0 },
+        },
+    )
+}
+
+fn part2(contraption: &Contraption) -> usize {
+    let field = &contraption.field;
+    let (width, height) = (field.width(), field.height());
+
+    let mut res = 0;
+
+    /* top & bottom */
+    for i in 0..width {
+        res = res.max(simulate(
+            field,
+            Beam {
+                coord: Coord { row: 0, col: i },
+                dir: Direction::South,
+            },
+        ));
+        res = res.max(simulate(
+            field,
+            Beam {
+                coord: Coord {
+                    row: height - 1,
+                    col: i,
+                },
+                dir: Direction::North,
+            },
+        ));
+    }
+    /* left & right */
+    for i in 0..height {
+        res = res.max(simulate(
+            field,
+            Beam {
+                coord: Coord { row: i, col: 0 },
+                dir: Direction::East,
+            },
+        ));
+        res = res.max(simulate(
+            field,
+            Beam {
+                coord: Coord {
+                    row: i,
+                    col: width - 1,
+                },
+                dir: Direction::West,
+            },
+        ));
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::solution::Solution;
+
+    use crate::{part1, part2, Day16};
+
+    #[test]
+    fn test_part1() {
+        let input = concat!(
+            ".|...\\....\n",
+            "|.-.\\.....\n",
+            ".....|-...\n",
+            "........|.\n",
+            "..........\n",
+            ".........\\\n",
+            "..../.\\\\..\n",
+            ".-.-/..|..\n",
+            ".|....-|.\\\n",
+            "..//.|....\n",
+        );
+        assert_eq!(part1(&Day16::parse(input).unwrap()), 46);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = concat!(
+            ".|...\\....\n",
+            "|.-.\\.....\n",
+            ".....|-...\n",
+            "........|.\n",
+            "..........\n",
+            ".........\\\n",
+            "..../.\\\\..\n",
+            ".-.-/..|..\n",
+            ".|....-|.\\\n",
+            "..//.|....\n",
+        );
+        assert_eq!(part2(&Day16::parse(input).unwrap()), 51);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day16::parse(".|..\n..+.\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 3, "+"));
+    }
+}
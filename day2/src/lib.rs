@@ -0,0 +1,174 @@
+use std::cmp::max;
+use std::str::FromStr;
+
+use aoc_common::{
+    parse::{number, split_once, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Default)]
+struct Pull {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+struct Config {
+    max_red: u32,
+    max_green: u32,
+    max_blue: u32,
+}
+
+pub struct Game {
+    id: u32,
+    pulls: Vec<Pull>,
+}
+
+pub struct Day2;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .map(|l| Game::from_str(l).map_err(|e| e.within(input, l)))
+            .collect()
+    }
+
+    fn part1(games: &Self::Input) -> Result<Answer, Self::Error> {
+        let config = Config {
+            max_red: 12,
+            max_green: 13,
+            max_blue: 14,
+        };
+        Ok(games
+            .iter()
+            .filter(|g| config.is_possible(g))
+            .fold(0u32, |a, g| a + g.id)
+            .into())
+    }
+
+    fn part2(games: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(games.iter().map(|g| g.min_power()).sum::<u32>().into())
+    }
+}
+
+impl Config {
+    fn is_possible(&self, game: &Game) -> bool {
+        game.pulls
+            .iter()
+            .all(|p| p.red <= self.max_red && p.green <= self.max_green && p.blue <= self.max_blue)
+    }
+}
+
+impl Game {
+    fn min_power(&self) -> u32 {
+        let mins = self
+            .pulls
+            .iter()
+            .map(|p| (p.red, p.green, p.blue))
+            .reduce(|acc, e| (max(acc.0, e.0), max(acc.1, e.1), max(acc.2, e.2)))
+            .unwrap();
+        mins.0 * mins.1 * mins.2
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game, pulls) = split_once(s, s, ':')?;
+        let id = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::new(s, game, "'Game <id>'"))?;
+        let id = number(s, id)?;
+        let pulls = pulls
+            .split(';')
+            .map(|p| Pull::parse(s, p))
+            .collect::<Result<_, _>>()?;
+        Ok(Game { id, pulls })
+    }
+}
+
+impl Pull {
+    fn parse(line: &str, s: &str) -> Result<Self, ParseError> {
+        let mut pull = Self::default();
+        for cubes in s.split(',').map(|s| s.trim()) {
+            let (num, col) = cubes
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(line, cubes, "'<count> <colour>'"))?;
+            let num = number(line, num)?;
+
+            match col {
+                "red" => pull.red = num,
+                "green" => pull.green = num,
+                "blue" => pull.blue = num,
+                _ => return Err(ParseError::new(line, col, "red, green or blue")),
+            }
+        }
+        Ok(pull)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use aoc_common::solution::Solution;
+
+    use crate::Config;
+    use crate::Day2;
+    use crate::Game;
+
+    #[test]
+    fn test_part_1() {
+        let lines = [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+            "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+        ];
+        let config = Config {
+            max_red: 12,
+            max_green: 13,
+            max_blue: 14,
+        };
+        let sum_passed: u32 = lines
+            .iter()
+            .map(|s| Game::from_str(s).unwrap())
+            .filter(|g| config.is_possible(g))
+            .fold(0, |a, g| a + g.id);
+        assert_eq!(sum_passed, 8);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let lines = [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+            "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+        ];
+        let sum_powers: u32 = lines
+            .iter()
+            .map(|s| Game::from_str(s).unwrap())
+            .map(|g| g.min_power())
+            .sum();
+        assert_eq!(sum_powers, 2286);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Game 1: 3 blue\nGame 2: 1 blue, 2 grey";
+        let e = Day2::parse(input).err().unwrap();
+        assert_eq!((e.line, e.column), (2, 19));
+        assert_eq!(e.found, "grey");
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use aoc_common::{
+    parse::{number, split_once, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Card {
+    id: u32,
+    numbers: HashSet<u32>,
+    winning_numbers: HashSet<u32>,
+}
+
+fn set_from_string(line: &str, s: &str) -> Result<HashSet<u32>, ParseError> {
+    s.split_whitespace().map(|s| number(line, s)).collect()
+}
+
+pub struct Day4;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .map(|l| Card::from_str(l).map_err(|e| e.within(input, l)))
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part1(cards).into())
+    }
+
+    fn part2(cards: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part2(cards).into())
+    }
+}
+
+fn part1(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|c| c.count())
+        .filter(|c| c > &0)
+        .map(|c| 2u32.pow(c - 1))
+        .sum()
+}
+
+fn part2(cards: &[Card]) -> u32 {
+    let mut cardcounts: HashMap<u32, u32> = cards.iter().map(|c| (c.id, 1)).collect();
+    for card in cards {
+        let currcount = cardcounts.get(&card.id).unwrap().to_owned();
+        for i in 0..card.count() {
+            let key: u32 = card.id + i + 1;
+            if let Some(x) = cardcounts.get_mut(&key) {
+                *x += currcount;
+            }
+        }
+    }
+    cardcounts.values().sum()
+}
+
+impl Card {
+    fn count(&self) -> u32 {
+        self.numbers.intersection(&self.winning_numbers).count() as u32
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, numbers) = split_once(s, s, ':')?;
+        let (numbers, winning_numbers) = split_once(s, numbers, '|')?;
+        let numbers = set_from_string(s, numbers)?;
+        let winning_numbers = set_from_string(s, winning_numbers)?;
+        let id = id
+            .strip_prefix("Card")
+            .ok_or_else(|| ParseError::new(s, id, "'Card <id>'"))?;
+        let id = number(s, id.trim())?;
+        Ok(Card {
+            id,
+            numbers,
+            winning_numbers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::solution::Solution;
+
+    use crate::{part1, part2, Day4};
+
+    #[test]
+    fn test_part_1() {
+        let lines = [
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+            "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19",
+            "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+            "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83",
+            "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36",
+            "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+        ];
+        let cards = Day4::parse(&lines.join("\n")).unwrap();
+        assert_eq!(part1(&cards), 13);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let lines = [
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+            "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19",
+            "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+            "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83",
+            "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36",
+            "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+        ];
+        let cards = Day4::parse(&lines.join("\n")).unwrap();
+        assert_eq!(part2(&cards), 30);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30";
+        let e = Day4::parse(input).err().unwrap();
+        assert_eq!((e.line, e.column), (2, 12));
+        assert_eq!(e.found, "x2");
+        assert_eq!(e.expected, "a number");
+    }
+}
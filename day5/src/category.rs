@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use crate::{
+    map::{Map, ParseErr},
+    value::Value,
+};
+
+pub struct CategoryGraph<T = u64> {
+    maps: Vec<Map<T>>,
+    // source category -> indices of the maps converting from it
+    edges: HashMap<String, Vec<usize>>,
+}
+
+impl<T: Value> CategoryGraph<T> {
+    pub fn new(maps: Vec<Map<T>>) -> CategoryGraph<T> {
+        let mut edges: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, map) in maps.iter().enumerate() {
+            edges.entry(map.source().to_string()).or_default().push(i);
+        }
+        CategoryGraph { maps, edges }
+    }
+
+    pub fn maps(&self) -> &[Map<T>] {
+        &self.maps
+    }
+
+    pub fn chain(&self, from: &str, to: &str) -> Result<Vec<&Map<T>>, ParseErr> {
+        let mut found = vec![];
+        self.find_chains(from, from, to, &mut vec![], &mut found);
+
+        match found.as_slice() {
+            [] => Err(ParseErr::NoChain(from.to_string(), to.to_string())),
+            [chain] => Ok(chain.iter().map(|i| &self.maps[*i]).collect()),
+            _ => Err(ParseErr::AmbiguousChain(from.to_string(), to.to_string())),
+        }
+    }
+
+    // Collects the chains from `at` to `to` that do not visit a category
+    // twice, stopping as soon as a second one shows up.
+    fn find_chains(
+        &self,
+        start: &str,
+        at: &str,
+        to: &str,
+        chain: &mut Vec<usize>,
+        found: &mut Vec<Vec<usize>>,
+    ) {
+        if at == to {
+            found.push(chain.clone());
+            return;
+        }
+
+        for &i in self.edges.get(at).into_iter().flatten() {
+            if found.len() > 1 {
+                return;
+            }
+            let target = self.maps[i].target();
+            if target == start || chain.iter().any(|j| self.maps[*j].target() == target) {
+                continue;
+            }
+            chain.push(i);
+            self.find_chains(start, target, to, chain, found);
+            chain.pop();
+        }
+    }
+
+    pub fn conversion(&self, from: &str, to: &str) -> Result<Map<T>, ParseErr> {
+        let chain = self.chain(from, to)?;
+        Ok(match chain.split_first() {
+            Some((first, rest)) => rest
+                .iter()
+                .fold((*first).clone(), |conversion, map| conversion.compose(map)),
+            None => Map::identity(from),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{map::Map, ParseErr};
+
+    use super::CategoryGraph;
+
+    fn categories(blocks: &[&str]) -> CategoryGraph {
+        CategoryGraph::new(blocks.iter().map(|b| Map::from_str(b).unwrap()).collect())
+    }
+
+    #[test]
+    fn category_chain() {
+        let graph = categories(&[
+            "b-to-c map:\n0 10 5",
+            "a-to-b map:\n10 0 5",
+            "c-to-d map:\n100 0 5",
+        ]);
+        let names: Vec<_> = graph
+            .chain("a", "d")
+            .unwrap()
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["a-to-b", "b-to-c", "c-to-d"]);
+
+        let conversion = graph.conversion("a", "d").unwrap();
+        assert_eq!(conversion.name, "a-to-d");
+        assert_eq!(conversion.apply(3), 103);
+        assert_eq!(graph.conversion("b", "b").unwrap().apply(7), 7);
+    }
+
+    #[test]
+    fn category_chain_errors() {
+        let graph = categories(&["a-to-b map:\n0 1 1", "c-to-d map:\n0 1 1"]);
+        assert!(matches!(graph.chain("a", "d"), Err(ParseErr::NoChain(..))));
+        assert!(matches!(graph.chain("b", "a"), Err(ParseErr::NoChain(..))));
+
+        let graph = categories(&[
+            "a-to-b map:\n0 1 1",
+            "b-to-a map:\n0 1 1",
+            "b-to-c map:\n0 1 1",
+            "a-to-c map:\n0 1 1",
+        ]);
+        assert!(matches!(
+            graph.chain("a", "c"),
+            Err(ParseErr::AmbiguousChain(..))
+        ));
+        assert_eq!(graph.chain("b", "a").unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,323 @@
+mod category;
+mod map;
+mod range_set;
+mod value;
+
+use std::{ops::Range, str::FromStr};
+
+use aoc_common::{
+    input::blocks,
+    parse::{number, ParseError},
+    solution::{Answer, Solution},
+};
+use itertools::Itertools;
+use value::{checked_range, width};
+
+pub use category::CategoryGraph;
+pub use map::{InverseMap, Map, MapIssue, ParseErr};
+pub use range_set::RangeSet;
+pub use value::Value;
+
+pub struct Almanac<T = u64> {
+    seeds: Vec<T>,
+    categories: CategoryGraph<T>,
+}
+
+pub struct Day5;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Error = ParseErr;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_almanac(input)
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part1(almanac)?.ok_or(ParseErr::NoSeeds)?.into())
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part2(almanac)?.ok_or(ParseErr::NoSeeds)?.into())
+    }
+}
+
+pub fn parse_almanac<T: Value>(input: &str) -> Result<Almanac<T>, ParseErr> {
+    let mut blocks = blocks(input);
+    let seeds = seeds_from_line(input, blocks.next().unwrap_or_default())?;
+    let maps = blocks
+        .map(|block| Map::from_str(block).map_err(|e| e.within(input, block)))
+        .collect::<Result<_, _>>()?;
+    Ok(Almanac {
+        seeds,
+        categories: CategoryGraph::new(maps),
+    })
+}
+
+impl<T: Value> Almanac<T> {
+    pub fn maps(&self) -> &[Map<T>] {
+        self.categories.maps()
+    }
+
+    pub fn categories(&self) -> &CategoryGraph<T> {
+        &self.categories
+    }
+}
+
+fn seeds_from_line<T: Value>(input: &str, line: &str) -> Result<Vec<T>, ParseError> {
+    let seeds = line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::new(input, line, "'seeds:'"))?;
+    seeds.split_whitespace().map(|s| number(input, s)).collect()
+}
+
+fn seed_ranges<T: Value>(seeds: &[T]) -> Result<Vec<Range<T>>, ParseErr> {
+    let mut ranges: Vec<Range<T>> = vec![];
+    for chunk in &seeds.iter().chunks(2) {
+        let range = chunk.collect::<Vec<&T>>();
+        let (&min, &len) = match range[..] {
+            [min, len] => (min, len),
+            _ => return Err(ParseErr::OddSeedCount),
+        };
+        ranges.push(checked_range(min, len).ok_or_else(|| {
+            ParseErr::Overflow(format!(
+                "seed range {} with length {} in {}",
+                min,
+                len,
+                width::<T>()
+            ))
+        })?);
+    }
+    Ok(ranges)
+}
+
+fn find_min_after_apply_single<T: Value>(seeds: &[T], maps: &[Map<T>]) -> Option<T> {
+    seeds
+        .iter()
+        .map(|s| -> T {
+            let mut i: T = *s;
+            for map in maps.iter() {
+                i = map.apply(i);
+            }
+            i
+        })
+        .min()
+}
+
+fn find_min_after_apply_ranges<T: Value>(seeds: &[Range<T>], maps: &[&Map<T>]) -> Option<T> {
+    let mut ranges: RangeSet<T> = seeds.iter().cloned().collect();
+    for map in maps {
+        ranges = map.apply_ranges(&ranges);
+    }
+    ranges.min()
+}
+
+fn part1<T: Value>(almanac: &Almanac<T>) -> Result<Option<T>, ParseErr> {
+    let chain = almanac.categories.conversion("seed", "location")?;
+    Ok(find_min_after_apply_single(
+        &almanac.seeds,
+        std::slice::from_ref(&chain),
+    ))
+}
+
+fn part2<T: Value>(almanac: &Almanac<T>) -> Result<Option<T>, ParseErr> {
+    let ranges = seed_ranges(&almanac.seeds)?;
+    let chain = almanac.categories.chain("seed", "location")?;
+    Ok(find_min_after_apply_ranges(&ranges, &chain))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input::blocks, solution::Solution};
+
+    use crate::{
+        find_min_after_apply_single, parse_almanac, part1, part2, seed_ranges, Day5, ParseErr,
+        EXAMPLE,
+    };
+
+    #[test]
+    fn test_part_1() {
+        let lines = [
+            "seeds: 79 14 55 13",
+            "",
+            "seed-to-soil map:",
+            "50 98 2",
+            "52 50 48",
+            "",
+            "soil-to-fertilizer map:",
+            "0 15 37",
+            "37 52 2",
+            "39 0 15",
+            "",
+            "fertilizer-to-water map:",
+            "49 53 8",
+            "0 11 42",
+            "42 0 7",
+            "57 7 4",
+            "",
+            "water-to-light map:",
+            "88 18 7",
+            "18 25 70",
+            "",
+            "light-to-temperature map:",
+            "45 77 23",
+            "81 45 19",
+            "68 64 13",
+            "",
+            "temperature-to-humidity map:",
+            "0 69 1",
+            "1 0 69",
+            "",
+            "humidity-to-location map:",
+            "60 56 37",
+            "56 93 4",
+        ];
+        let almanac = Day5::parse(&lines.join("\n")).unwrap();
+        assert_eq!(part1(&almanac).unwrap().unwrap(), 35);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let lines = [
+            "seeds: 79 14 55 13",
+            "",
+            "seed-to-soil map:",
+            "50 98 2",
+            "52 50 48",
+            "",
+            "soil-to-fertilizer map:",
+            "0 15 37",
+            "37 52 2",
+            "39 0 15",
+            "",
+            "fertilizer-to-water map:",
+            "49 53 8",
+            "0 11 42",
+            "42 0 7",
+            "57 7 4",
+            "",
+            "water-to-light map:",
+            "88 18 7",
+            "18 25 70",
+            "",
+            "light-to-temperature map:",
+            "45 77 23",
+            "81 45 19",
+            "68 64 13",
+            "",
+            "temperature-to-humidity map:",
+            "0 69 1",
+            "1 0 69",
+            "",
+            "humidity-to-location map:",
+            "60 56 37",
+            "56 93 4",
+        ];
+        let almanac = Day5::parse(&lines.join("\n")).unwrap();
+        assert_eq!(part2(&almanac).unwrap().unwrap(), 46)
+    }
+
+    #[test]
+    fn test_compose_chain() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let chain = almanac.categories().conversion("seed", "location").unwrap();
+        assert_eq!(chain.name, "seed-to-location");
+        for seed in 0..120 {
+            assert_eq!(
+                find_min_after_apply_single(&[seed], std::slice::from_ref(&chain)),
+                find_min_after_apply_single(&[seed], almanac.maps()),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_inverse_chain() {
+        let almanac = Day5::parse(EXAMPLE).unwrap();
+        let chain = almanac.categories().conversion("seed", "location").unwrap();
+        let inverse = chain.invert();
+        assert_eq!(inverse.name, "location-to-seed");
+
+        let seeds = seed_ranges(&almanac.seeds).unwrap();
+        let lowest = inverse.lowest_reachable(&seeds);
+        assert_eq!(lowest, Some((46, 82)));
+        assert_eq!(
+            lowest.map(|(location, _)| location),
+            part2(&almanac).unwrap()
+        );
+
+        let preimage = inverse.preimage(&(0..50));
+        for seed in 0..120 {
+            let in_preimage = preimage.contains(seed);
+            assert_eq!(in_preimage, chain.apply(seed) < 50, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_shuffled_maps() {
+        let mut parts: Vec<_> = blocks(EXAMPLE).collect();
+        parts[1..].reverse();
+        let almanac = Day5::parse(&parts.join("\n\n")).unwrap();
+        assert_eq!(almanac.maps()[0].name, "humidity-to-location");
+        assert_eq!(part1(&almanac).unwrap(), Some(35));
+        assert_eq!(part2(&almanac).unwrap(), Some(46));
+
+        let soil_to_humidity = almanac.categories().conversion("soil", "humidity").unwrap();
+        let chain = almanac.categories().chain("soil", "humidity").unwrap();
+        assert_eq!(chain.len(), 5);
+        for soil in 0..120 {
+            let expected = chain.iter().fold(soil, |v, map| map.apply(v));
+            assert_eq!(soil_to_humidity.apply(soil), expected);
+        }
+
+        parts.remove(3);
+        let almanac = Day5::parse(&parts.join("\n\n")).unwrap();
+        assert!(matches!(part1(&almanac), Err(ParseErr::NoChain(..))));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "seeds: 79 14 5x 13\n\nseed-to-soil map:\n50 98 2\n";
+        match Day5::parse(input).err().unwrap() {
+            ParseErr::Parse(e) => {
+                assert_eq!((e.line, e.column), (1, 14));
+                assert_eq!(e.found, "5x");
+            }
+            e => panic!("unexpected error {}", e),
+        }
+
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n";
+        match Day5::parse(input).err().unwrap() {
+            ParseErr::Parse(e) => assert_eq!((e.line, e.column), (5, 6)),
+            e => panic!("unexpected error {}", e),
+        }
+    }
+
+    #[test]
+    fn test_widths() {
+        let almanac = parse_almanac::<u32>(EXAMPLE).unwrap();
+        assert_eq!(part1(&almanac).unwrap(), Some(35));
+        assert_eq!(part2(&almanac).unwrap(), Some(46));
+
+        let input = "seeds: 4294967290 10\n\nseed-to-location map:\n0 4294967290 5\n";
+        let almanac = parse_almanac::<u32>(input).unwrap();
+        assert_eq!(part1(&almanac).unwrap(), Some(0));
+        assert!(matches!(part2(&almanac), Err(ParseErr::Overflow(..))));
+
+        let input = "seeds: 100000000000000000000 5\n\n\
+                     seed-to-location map:\n\
+                     7 100000000000000000002 300000000000000000000\n";
+        assert!(matches!(
+            parse_almanac::<u64>(input),
+            Err(ParseErr::Parse(_))
+        ));
+        let almanac = parse_almanac::<u128>(input).unwrap();
+        assert_eq!(part1(&almanac).unwrap(), Some(5));
+        assert_eq!(part2(&almanac).unwrap(), Some(7));
+    }
+}
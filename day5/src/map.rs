@@ -1,38 +1,76 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::Display;
 use std::ops::Range;
 use std::str::FromStr;
 
+use aoc_common::parse::{number, ParseError};
+
+use crate::{
+    range_set::RangeSet,
+    value::{checked_range, width, Value},
+};
+
 #[derive(Debug)]
 pub enum ParseErr {
-    InvalidInput,
+    Parse(ParseError),
+    NoSeeds,
+    OddSeedCount,
+    NoChain(String, String),
+    AmbiguousChain(String, String),
+    Overflow(String),
 }
 
-#[derive(PartialEq, Debug)]
-struct MappingRange {
-    source_range: Range<u64>,
-    destination_range_start: u64,
+// both ranges fit in T, which is checked when parsing
+#[derive(Clone, PartialEq, Debug)]
+struct MappingRange<T> {
+    source_range: Range<T>,
+    destination_range_start: T,
 }
 
-#[derive(PartialEq, Debug)]
-pub struct Map {
+#[derive(Clone, PartialEq, Debug)]
+pub struct Map<T = u64> {
+    pub name: String,
+    ranges: Vec<MappingRange<T>>,
+    // the ranges cut into disjoint pieces where the first one wins, sorted by
+    // source so a value is looked up with a binary search
+    sorted: Vec<MappingRange<T>>,
+}
+
+pub struct InverseMap<T = u64> {
     pub name: String,
-    ranges: Vec<MappingRange>,
+    // forward pieces with source and destination swapped, several of them
+    // may overlap where the forward map is not injective
+    pieces: Vec<MappingRange<T>>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum MapIssue<T = u64> {
+    OverlappingSources(Range<T>, Range<T>),
+    OverlappingDestinations(Range<T>, Range<T>),
+    Gap(Range<T>),
 }
 
 #[derive(PartialEq, Debug)]
-pub struct ApplyRangeResult {
-    matched: Option<Range<u64>>,
-    unmatched: Vec<Range<u64>>,
+pub struct ApplyRangeResult<T> {
+    matched: Option<Range<T>>,
+    unmatched: Vec<Range<T>>,
 }
 
-pub fn range_from_start_len(start: u64, len: u64) -> Range<u64> {
-    Range {
-        start,
-        end: start + len,
+impl<T: Value> MappingRange<T> {
+    fn destination_range(&self) -> Range<T> {
+        let len = self.source_range.end - self.source_range.start;
+        self.destination_range_start..self.destination_range_start + len
+    }
+
+    // maps a part of the source range
+    fn image(&self, part: Range<T>) -> Range<T> {
+        let start = self.source_range.start;
+        self.destination_range_start + (part.start - start)
+            ..self.destination_range_start + (part.end - start)
     }
-}
 
-impl MappingRange {
-    fn apply(&self, input: u64) -> Option<u64> {
+    fn apply(&self, input: T) -> Option<T> {
         if !self.source_range.contains(&input) {
             None
         } else {
@@ -40,147 +78,400 @@ impl MappingRange {
         }
     }
 
-    fn apply_range(&self, range: &Range<u64>) -> ApplyRangeResult {
-        let range = range.clone();
-        let mut unmatched = vec![];
-        let mut matched = None;
+    // Splits range into the part inside the source range, which is mapped,
+    // and the parts below and above it, any of which may be empty.
+    fn apply_range(&self, range: &Range<T>) -> ApplyRangeResult<T> {
+        let source = &self.source_range;
+        let start = range.start.max(source.start);
+        let end = range.end.min(source.end);
+        let matched = (start < end).then(|| self.image(start..end));
 
-        // range is fully contained within
-        if self.source_range.start <= range.start && self.source_range.end >= range.end {
-            matched = Some(range_from_start_len(
-                self.apply(range.start).unwrap(),
-                range.count() as u64,
-            ));
+        let below = range.start..range.end.min(source.start);
+        let above = range.start.max(source.end)..range.end;
+        let unmatched = [below, above]
+            .into_iter()
+            .filter(|r| !r.is_empty())
+            .collect();
+
+        ApplyRangeResult { matched, unmatched }
+    }
+}
+
+impl Display for ParseErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErr::Parse(e) => Display::fmt(e, f),
+            ParseErr::NoSeeds => f.write_str("no seeds"),
+            ParseErr::OddSeedCount => f.write_str("seeds do not come in start/length pairs"),
+            ParseErr::NoChain(from, to) => write!(f, "no chain of maps from {} to {}", from, to),
+            ParseErr::AmbiguousChain(from, to) => {
+                write!(f, "more than one chain of maps from {} to {}", from, to)
+            }
+            ParseErr::Overflow(what) => write!(f, "{} overflows", what),
         }
-        // range is fully outside
-        else if self.source_range.end <= range.start || self.source_range.start >= range.end {
-            unmatched.push(range);
+    }
+}
+
+impl<T: Value> Display for MapIssue<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapIssue::OverlappingSources(a, b) => write!(
+                f,
+                "source ranges {:?} and {:?} overlap, the first one wins",
+                a, b
+            ),
+            MapIssue::OverlappingDestinations(a, b) => write!(
+                f,
+                "destination ranges {:?} and {:?} overlap, the map is not injective",
+                a, b
+            ),
+            MapIssue::Gap(r) => write!(f, "{:?} is not mapped and stays as it is", r),
         }
-        // range is split by this' start
-        else if self.source_range.start > range.start && self.source_range.end >= range.end {
-            unmatched.push(Range {
-                start: range.start,
-                end: self.source_range.start,
-            });
-            matched = Some(Range {
-                start: self.apply(self.source_range.start).unwrap(),
-                end: self.apply(range.end - 1).unwrap() + 1,
-            });
+    }
+}
+
+impl From<ParseError> for ParseErr {
+    fn from(value: ParseError) -> Self {
+        ParseErr::Parse(value)
+    }
+}
+
+impl<T: Value> FromStr for MappingRange<T> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let mut nums = [T::zero(); 3];
+        let mut len_word = "";
+        for num in nums.iter_mut() {
+            len_word = words
+                .next()
+                .ok_or_else(|| ParseError::new(s, &s[s.len()..], "three numbers"))?;
+            *num = number(s, len_word)?;
         }
-        // range is split by this' end
-        else if self.source_range.start <= range.start && self.source_range.end < range.end {
-            matched = Some(Range {
-                start: self.apply(range.start).unwrap(),
-                end: self.apply(self.source_range.end - 1).unwrap() + 1,
-            });
-            unmatched.push(Range {
-                start: self.source_range.end,
-                end: range.end,
-            });
+        if let Some(extra) = words.next() {
+            return Err(ParseError::new(s, extra, "the end of the line"));
         }
-        // this is fully contained in range
-        else if self.source_range.start > range.start && self.source_range.end < range.end {
-            matched = Some(Range {
-                start: self.apply(self.source_range.start).unwrap(),
-                end: self.apply(self.source_range.end - 1).unwrap() + 1,
-            });
-            unmatched.push(Range {
-                start: range.start,
-                end: self.source_range.start,
-            });
-            unmatched.push(Range {
-                start: self.source_range.end,
-                end: range.end,
+
+        let [destination, source, len] = nums;
+        let overflow = || {
+            let expected = format!("a length that keeps both ranges within {}", width::<T>());
+            ParseError::new(s, len_word, expected)
+        };
+        checked_range(destination, len).ok_or_else(overflow)?;
+        Ok(MappingRange {
+            destination_range_start: destination,
+            source_range: checked_range(source, len).ok_or_else(overflow)?,
+        })
+    }
+}
+
+impl<T: Value> Map<T> {
+    fn new(name: String, ranges: Vec<MappingRange<T>>) -> Map<T> {
+        let sorted = resolve(&ranges);
+        Map {
+            name,
+            ranges,
+            sorted,
+        }
+    }
+
+    pub fn apply(&self, input: T) -> T {
+        let i = self.sorted.partition_point(|r| r.source_range.end <= input);
+        self.sorted
+            .get(i)
+            .and_then(|r| r.apply(input))
+            .unwrap_or(input)
+    }
+
+    // Sweeps the sorted input ranges and the sorted pieces side by side.
+    pub fn apply_ranges(&self, ranges: &RangeSet<T>) -> RangeSet<T> {
+        let mut image = vec![];
+        let mut pieces = self.sorted.iter().peekable();
+
+        for range in ranges.ranges() {
+            let mut start = range.start;
+            while start < range.end {
+                while pieces.next_if(|p| p.source_range.end <= start).is_some() {}
+                let end = match pieces.peek() {
+                    Some(p) if p.source_range.start <= start => {
+                        let end = p.source_range.end.min(range.end);
+                        image.push(p.image(start..end));
+                        end
+                    }
+                    Some(p) => {
+                        let end = p.source_range.start.min(range.end);
+                        image.push(start..end);
+                        end
+                    }
+                    None => {
+                        image.push(start..range.end);
+                        range.end
+                    }
+                };
+                start = end;
+            }
+        }
+
+        image.into_iter().collect()
+    }
+
+    // Splits the whole domain into pieces that are each shifted by a single
+    // offset, with the unmapped gaps as identity pieces.
+    fn pieces(&self) -> Vec<MappingRange<T>> {
+        let mut pieces = vec![];
+        let mut start = T::zero();
+        for r in &self.sorted {
+            if start < r.source_range.start {
+                pieces.push(MappingRange {
+                    source_range: start..r.source_range.start,
+                    destination_range_start: start,
+                });
+            }
+            pieces.push(r.clone());
+            start = r.source_range.end;
+        }
+        if start < T::max_value() {
+            pieces.push(MappingRange {
+                source_range: start..T::max_value(),
+                destination_range_start: start,
             });
         }
-        // hopefully this doesn't happen
-        else {
-            panic!("self: {:?} range: {:?}", self.source_range, range);
+        pieces
+    }
+
+    pub fn compose(&self, other: &Map<T>) -> Map<T> {
+        let mut ranges: Vec<MappingRange<T>> = vec![];
+        let next_pieces = other.pieces();
+
+        for piece in self.pieces() {
+            let image = piece.destination_range();
+            let first = next_pieces.partition_point(|p| p.source_range.end <= image.start);
+            for next in &next_pieces[first..] {
+                if next.source_range.start >= image.end {
+                    break;
+                }
+                let start = image.start.max(next.source_range.start);
+                let end = image.end.min(next.source_range.end);
+                ranges.push(MappingRange {
+                    source_range: piece.source_range.start + (start - image.start)
+                        ..piece.source_range.start + (end - image.start),
+                    destination_range_start: next.apply(start).unwrap(),
+                });
+            }
+        }
+
+        ranges.sort_by_key(|r| r.source_range.start);
+        let mut merged: Vec<MappingRange<T>> = vec![];
+        for r in ranges {
+            if r.source_range.start == r.destination_range_start {
+                continue;
+            }
+            match merged.last_mut() {
+                Some(last)
+                    if last.source_range.end == r.source_range.start
+                        && last.apply(last.source_range.end - T::one()).unwrap() + T::one()
+                            == r.destination_range_start =>
+                {
+                    last.source_range.end = r.source_range.end;
+                }
+                _ => merged.push(r),
+            }
         }
 
-        return ApplyRangeResult { matched, unmatched };
+        Map::new(format!("{}-to-{}", self.source(), other.target()), merged)
     }
-}
 
-impl FromStr for MappingRange {
-    type Err = ParseErr;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let nums: Option<Vec<u64>> = s
-            .split(' ')
-            .map(|s| s.trim())
-            .map(|s| s.parse::<u64>())
-            .map(|r| r.ok())
+    pub fn identity(category: &str) -> Map<T> {
+        Map::new(format!("{0}-to-{0}", category), vec![])
+    }
+
+    pub fn source(&self) -> &str {
+        self.name
+            .split_once("-to-")
+            .map_or(&self.name, |(source, _)| source)
+    }
+
+    pub fn target(&self) -> &str {
+        self.name
+            .rsplit_once("-to-")
+            .map_or(&self.name, |(_, target)| target)
+    }
+
+    pub fn validate(&self) -> Vec<MapIssue<T>> {
+        let sources: Vec<_> = self.ranges.iter().map(|r| r.source_range.clone()).collect();
+        let destinations: Vec<_> = self.ranges.iter().map(|r| r.destination_range()).collect();
+
+        let mut issues: Vec<MapIssue<T>> = overlaps(&sources)
+            .into_iter()
+            .map(|(a, b)| MapIssue::OverlappingSources(a, b))
             .collect();
-        let nums = nums.ok_or(ParseErr::InvalidInput)?;
-        Ok(MappingRange {
-            destination_range_start: nums[0],
-            source_range: range_from_start_len(nums[1], nums[2]),
-        })
+        issues.extend(
+            overlaps(&destinations)
+                .into_iter()
+                .map(|(a, b)| MapIssue::OverlappingDestinations(a, b)),
+        );
+
+        let covered: RangeSet<T> = sources.into_iter().collect();
+        if let (Some(first), Some(last)) = (covered.ranges().first(), covered.ranges().last()) {
+            let gaps = RangeSet::from(first.start..last.end).difference(&covered);
+            issues.extend(gaps.ranges().iter().cloned().map(MapIssue::Gap));
+        }
+
+        issues
+    }
+
+    pub fn invert(&self) -> InverseMap<T> {
+        let mut pieces: Vec<MappingRange<T>> = self
+            .pieces()
+            .into_iter()
+            .map(|p| MappingRange {
+                source_range: p.destination_range(),
+                destination_range_start: p.source_range.start,
+            })
+            .collect();
+        pieces.sort_by_key(|p| p.source_range.start);
+
+        InverseMap {
+            name: format!("{}-to-{}", self.target(), self.source()),
+            pieces,
+        }
+    }
+}
+
+// Cuts possibly overlapping ranges into disjoint pieces, each taken from the
+// first range that covers it, by sweeping the bounds with the covering ranges
+// in a heap ordered by their position in the map.
+fn resolve<T: Value>(ranges: &[MappingRange<T>]) -> Vec<MappingRange<T>> {
+    let mut by_start: Vec<usize> = (0..ranges.len())
+        .filter(|&i| !ranges[i].source_range.is_empty())
+        .collect();
+    by_start.sort_by_key(|&i| ranges[i].source_range.start);
+    let mut bounds: Vec<T> = ranges
+        .iter()
+        .flat_map(|r| [r.source_range.start, r.source_range.end])
+        .collect();
+    bounds.sort();
+    bounds.dedup();
+
+    let mut resolved: Vec<MappingRange<T>> = vec![];
+    let mut covering = BinaryHeap::new();
+    let mut next = by_start.iter().peekable();
+    for w in bounds.windows(2) {
+        let (start, end) = (w[0], w[1]);
+        while let Some(&i) = next.next_if(|&&i| ranges[i].source_range.start <= start) {
+            covering.push(Reverse(i));
+        }
+        while covering
+            .peek()
+            .is_some_and(|&Reverse(i)| ranges[i].source_range.end <= start)
+        {
+            covering.pop();
+        }
+        let Some(&Reverse(i)) = covering.peek() else {
+            continue;
+        };
+
+        let piece = MappingRange {
+            source_range: start..end,
+            destination_range_start: ranges[i].apply(start).unwrap(),
+        };
+        match resolved.last_mut() {
+            Some(last)
+                if last.source_range.end == start
+                    && last.destination_range().end == piece.destination_range_start =>
+            {
+                last.source_range.end = end;
+            }
+            _ => resolved.push(piece),
+        }
     }
+    resolved
 }
 
-impl Map {
-    pub fn apply(&self, input: u64) -> u64 {
-        self.ranges
+fn overlaps<T: Value>(ranges: &[Range<T>]) -> Vec<(Range<T>, Range<T>)> {
+    let mut ranges: Vec<_> = ranges.iter().filter(|r| !r.is_empty()).cloned().collect();
+    ranges.sort_by_key(|r| r.start);
+
+    let mut found = vec![];
+    let mut furthest: Option<Range<T>> = None;
+    for r in ranges {
+        if let Some(f) = furthest.as_ref().filter(|f| f.end > r.start) {
+            found.push((f.clone(), r.clone()));
+        }
+        if furthest.as_ref().is_none_or(|f| r.end > f.end) {
+            furthest = Some(r);
+        }
+    }
+    found
+}
+
+impl<T: Value> InverseMap<T> {
+    pub fn preimage(&self, range: &Range<T>) -> RangeSet<T> {
+        self.pieces
             .iter()
-            .find_map(|r| r.apply(input))
-            .unwrap_or(input)
+            .filter_map(|p| p.apply_range(range).matched)
+            .collect()
     }
 
-    pub fn apply_ranges(&self, ranges: &mut Vec<Range<u64>>) {
-        let mut new_ranges: Vec<Range<u64>> = vec![];
-        let mut unmatched_ranges = ranges.clone();
+    // Walks the image upwards from 0 and returns the lowest value reached from
+    // any of the sources, together with the source it is reached from.
+    pub fn lowest_reachable(&self, sources: &[Range<T>]) -> Option<(T, T)> {
+        let mut best: Option<(T, T)> = None;
 
-        for mapping_range in &self.ranges {
-            let old_unmatched = unmatched_ranges.clone();
-            unmatched_ranges.clear();
-            for unmatched_range in &old_unmatched {
-                let mut result = mapping_range.apply_range(unmatched_range);
-                unmatched_ranges.append(&mut result.unmatched);
-                if result.matched.is_some() {
-                    new_ranges.push(result.matched.unwrap());
+        for piece in &self.pieces {
+            if best.is_some_and(|(value, _)| piece.source_range.start >= value) {
+                break;
+            }
+            let preimage = piece.destination_range();
+            let lowest_source = sources
+                .iter()
+                .filter(|s| s.start < preimage.end && preimage.start < s.end)
+                .map(|s| s.start.max(preimage.start))
+                .min();
+            if let Some(source) = lowest_source {
+                let value = piece.source_range.start + (source - preimage.start);
+                if best.is_none_or(|(v, _)| value < v) {
+                    best = Some((value, source));
                 }
             }
         }
 
-        new_ranges.append(&mut unmatched_ranges);
-        ranges.clear();
-        ranges.append(&mut new_ranges);
+        best
     }
 }
 
-impl TryFrom<Vec<String>> for Map {
-    type Error = ParseErr;
-    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
-        let name_line = value.get(0).ok_or(ParseErr::InvalidInput)?;
-        let name = name_line.split_once(' ').ok_or(ParseErr::InvalidInput)?.0;
-        let ranges: Option<Vec<MappingRange>> = value
-            .iter()
-            .skip(1)
-            .map(|s| MappingRange::from_str(s.as_str()))
-            .map(|r| r.ok())
-            .collect();
-        let ranges = ranges.ok_or(ParseErr::InvalidInput)?;
+impl<T: Value> FromStr for Map<T> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let header = lines.next().unwrap_or_default();
+        let name = header
+            .strip_suffix(" map:")
+            .filter(|name| name.contains("-to-"))
+            .ok_or_else(|| ParseError::new(s, header, "'<source>-to-<target> map:'"))?;
+        let ranges = lines
+            .map(|line| MappingRange::from_str(line).map_err(|e| e.within(s, line)))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(Self {
-            name: name.to_string(),
-            ranges,
-        })
+        Ok(Map::new(name.to_string(), ranges))
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
 mod tests {
-    use std::{collections::HashSet, ops::Range, str::FromStr};
+    use std::{ops::Range, str::FromStr};
+
+    use proptest::{collection::vec, prelude::*};
 
-    use crate::map::ApplyRangeResult;
+    use crate::{map::ApplyRangeResult, range_set::RangeSet};
 
-    use super::{Map, MappingRange};
+    use super::{Map, MapIssue, MappingRange};
 
     #[test]
     fn mapping_range_from_str() {
         let input = "50 98 2";
-        let range = MappingRange::from_str(input);
+        let range = MappingRange::<u64>::from_str(input);
         assert!(range.is_ok());
         let range = range.unwrap();
         assert_eq!(range.destination_range_start, 50);
@@ -196,7 +487,7 @@ mod tests {
     #[test]
     fn mapping_range_apply() {
         let input = "50 98 2";
-        let range = MappingRange::from_str(input).unwrap();
+        let range = MappingRange::<u64>::from_str(input).unwrap();
         assert_eq!(range.apply(97), None);
         assert_eq!(range.apply(98), Some(50));
         assert_eq!(range.apply(99), Some(51));
@@ -206,7 +497,7 @@ mod tests {
     #[test]
     fn mapping_range_apply_range() {
         let input = "52 50 48";
-        let range = MappingRange::from_str(input).unwrap();
+        let range = MappingRange::<u64>::from_str(input).unwrap();
         // fully inside
         assert_eq!(
             range.apply_range(&(50..98)),
@@ -246,45 +537,91 @@ mod tests {
                 unmatched: vec![(98..110)],
             }
         );
+        // range covers it
+        assert_eq!(
+            range.apply_range(&(0..200)),
+            ApplyRangeResult {
+                matched: Some(52..100),
+                unmatched: vec![(0..50), (98..200)],
+            }
+        );
+        // empty ranges, inside, outside and at the edges
+        for empty in [
+            (60..60),
+            (0..0),
+            (50..50),
+            (98..98),
+            Range { start: 70, end: 60 },
+        ] {
+            assert_eq!(
+                range.apply_range(&empty),
+                ApplyRangeResult {
+                    matched: None,
+                    unmatched: vec![],
+                }
+            );
+        }
+        let empty = MappingRange::<u64>::from_str("10 20 0").unwrap();
+        assert_eq!(
+            empty.apply_range(&(15..25)),
+            ApplyRangeResult {
+                matched: None,
+                unmatched: vec![(15..20), (20..25)],
+            }
+        );
     }
 
     #[test]
     fn map_from_vec() {
-        let input: Vec<String> = vec!["seed-to-soil map:", "50 98 2", "52 50 48"]
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
-        let map = Map::try_from(input);
+        let map = Map::<u64>::from_str("seed-to-soil map:\n50 98 2\n52 50 48");
         assert!(map.is_ok());
         let map = map.unwrap();
+        let first = MappingRange {
+            destination_range_start: 50,
+            source_range: Range {
+                start: 98,
+                end: 100,
+            },
+        };
+        let second = MappingRange {
+            destination_range_start: 52,
+            source_range: Range { start: 50, end: 98 },
+        };
+        assert_eq!(map.name, "seed-to-soil");
+        assert_eq!(map.ranges, vec![first.clone(), second.clone()]);
+        assert_eq!(map.sorted, vec![second, first]);
+    }
+
+    #[test]
+    fn map_parse_error() {
+        let e = Map::<u64>::from_str("seed-to-soil map:\n50 98 2\n52 5O 48").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 4));
+        assert_eq!(e.found, "5O");
+        let e = Map::<u64>::from_str("seed to soil map:\n50 98 2").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        let e = MappingRange::<u64>::from_str("50 98").unwrap_err();
         assert_eq!(
-            map,
-            Map {
-                name: "seed-to-soil".to_string(),
-                ranges: vec![
-                    MappingRange {
-                        destination_range_start: 50,
-                        source_range: Range {
-                            start: 98,
-                            end: 100
-                        }
-                    },
-                    MappingRange {
-                        destination_range_start: 52,
-                        source_range: Range { start: 50, end: 98 }
-                    }
-                ]
-            }
+            (e.line, e.column, e.expected.as_str()),
+            (1, 6, "three numbers")
         );
     }
 
+    #[test]
+    fn map_overflow() {
+        let input = "a-to-b map:\n0 4294967290 10";
+        let e = Map::<u32>::from_str(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 14, "10"));
+        assert_eq!(e.expected, "a length that keeps both ranges within u32");
+        assert!(Map::<u32>::from_str("a-to-b map:\n4294967290 0 10").is_err());
+        assert!(Map::<u32>::from_str("a-to-b map:\n0 4294967290 5").is_ok());
+
+        let map = Map::<u64>::from_str(input).unwrap();
+        assert_eq!(map.apply(4294967299), 9);
+    }
+
     #[test]
     fn map_apply() {
-        let input: Vec<String> = vec!["seed-to-soil map:", "50 98 2", "52 50 48"]
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
-        let map = Map::try_from(input);
+        let map = Map::<u64>::from_str("seed-to-soil map:\n50 98 2\n52 50 48");
         let map = map.unwrap();
         for i in 0..50 {
             assert_eq!(map.apply(i), i);
@@ -300,20 +637,143 @@ mod tests {
         }
     }
 
+    #[test]
+    fn map_overlapping_ranges() {
+        let input = "a-to-b map:\n0 10 5\n3 12 4\n100 20 5\n50 8 30\n7 30 0";
+        let map = Map::<u64>::from_str(input).unwrap();
+        let linear = |v: u64| map.ranges.iter().find_map(|r| r.apply(v)).unwrap_or(v);
+        for i in 0..50 {
+            assert_eq!(map.apply(i), linear(i), "value {}", i);
+        }
+
+        for (start, end) in [(0, 50), (9, 13), (14, 21), (24, 40)] {
+            let points: RangeSet = (start..end).map(|v| linear(v)..linear(v) + 1).collect();
+            let input: RangeSet = [(start..end), (45..47)].into_iter().collect();
+            let expected = points.union(&RangeSet::from(45..47));
+            assert_eq!(map.apply_ranges(&input), expected, "{}..{}", start, end);
+        }
+    }
+
+    #[test]
+    fn map_compose() {
+        let first = Map::<u64>::from_str("seed-to-soil map:\n50 98 2\n52 50 48").unwrap();
+        let second =
+            Map::<u64>::from_str("soil-to-fertilizer map:\n0 15 37\n37 52 2\n39 0 15").unwrap();
+        let composed = first.compose(&second);
+        assert_eq!(composed.name, "seed-to-fertilizer");
+        for i in 0..200 {
+            assert_eq!(
+                composed.apply(i),
+                second.apply(first.apply(i)),
+                "seed {}",
+                i
+            );
+        }
+        assert_eq!(composed.apply(u64::MAX - 1), u64::MAX - 1);
+    }
+
+    #[test]
+    fn map_validate() {
+        let map = Map::<u64>::from_str("seed-to-soil map:\n50 98 2\n52 50 48").unwrap();
+        assert_eq!(map.validate(), vec![]);
+
+        let map = Map::<u64>::from_str("a-to-b map:\n0 10 5\n3 12 4\n100 20 5").unwrap();
+        assert_eq!(
+            map.validate(),
+            vec![
+                MapIssue::OverlappingSources(10..15, 12..16),
+                MapIssue::OverlappingDestinations(0..5, 3..7),
+                MapIssue::Gap(16..20),
+            ]
+        );
+        assert_eq!(
+            map.validate()[2].to_string(),
+            "16..20 is not mapped and stays as it is"
+        );
+    }
+
+    #[test]
+    fn map_invert() {
+        let map = Map::<u64>::from_str("seed-to-soil map:\n50 98 2\n52 50 48").unwrap();
+        let inverse = map.invert();
+        assert_eq!(inverse.name, "soil-to-seed");
+        assert_eq!(inverse.preimage(&(50..52)).ranges(), &[98..100]);
+        assert_eq!(inverse.preimage(&(60..70)).ranges(), &[58..68]);
+        assert_eq!(inverse.preimage(&(0..10)).ranges(), &[0..10]);
+
+        for range in [0..10, 45..60, 95..105] {
+            for seed in inverse.preimage(&range).ranges().iter().cloned().flatten() {
+                assert!(range.contains(&map.apply(seed)));
+            }
+        }
+
+        assert_eq!(inverse.lowest_reachable(&[(96..99)]), Some((50, 98)));
+        assert_eq!(
+            inverse.lowest_reachable(&[(60..62), (10..11)]),
+            Some((10, 10))
+        );
+        assert_eq!(inverse.lowest_reachable(&[]), None);
+
+        // 0..5 is reached both from 10..15 and, unmapped, from itself
+        let map = Map::<u64>::from_str("a-to-b map:\n0 10 5").unwrap();
+        assert_eq!(map.invert().preimage(&(2..7)).ranges(), &[2..7, 12..15]);
+    }
+
     #[test]
     fn map_apply_range() {
-        let input: Vec<String> = vec!["seed-to-soil map:", "50 98 2", "52 50 48"]
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
-        let map = Map::try_from(input);
+        let map = Map::<u64>::from_str("seed-to-soil map:\n50 98 2\n52 50 48");
         let map = map.unwrap();
-        let mut input = vec![(0..150)];
-        map.apply_ranges(&mut input);
-        let mut input_set = HashSet::new();
-        input_set.extend(input);
-        let mut expected = HashSet::new();
-        expected.extend(vec![(0..50), (52..100), (50..52), (100..150)]);
-        assert_eq!(input_set, expected);
+        let output = map.apply_ranges(&RangeSet::from(0..150));
+        assert_eq!(output.ranges(), &[(0..150)]);
+
+        let input: RangeSet = [(40..60), (95..99)].into_iter().collect();
+        let output = map.apply_ranges(&input);
+        assert_eq!(output.ranges(), &[(40..51), (52..62), (97..100)]);
+    }
+
+    fn mapping_range() -> impl Strategy<Value = MappingRange<u64>> {
+        (0..200u64, 0..200u64, 0..30u64).prop_map(|(destination, source, len)| MappingRange {
+            destination_range_start: destination,
+            source_range: source..source + len,
+        })
+    }
+
+    fn small_range() -> impl Strategy<Value = Range<u64>> {
+        (0..250u64, 0..40u64).prop_map(|(start, len)| start..start + len)
+    }
+
+    proptest! {
+        #[test]
+        fn apply_range_is_pointwise(mapping in mapping_range(), range in small_range()) {
+            let result = mapping.apply_range(&range);
+            let mut matched: Vec<u64> = result.matched.into_iter().flatten().collect();
+            let mut unmatched: Vec<u64> = result.unmatched.into_iter().flatten().collect();
+            matched.sort();
+            unmatched.sort();
+
+            let expected_matched: Vec<u64> = range.clone().filter_map(|v| mapping.apply(v)).collect();
+            let expected_unmatched: Vec<u64> =
+                range.filter(|v| mapping.apply(*v).is_none()).collect();
+            prop_assert_eq!(matched, expected_matched);
+            prop_assert_eq!(unmatched, expected_unmatched);
+        }
+
+        #[test]
+        fn apply_ranges_is_pointwise(
+            ranges in vec(mapping_range(), 0..8),
+            inputs in vec(small_range(), 0..6),
+        ) {
+            let map = Map::new("a-to-b".to_string(), ranges);
+            let input: RangeSet = inputs.iter().cloned().collect();
+            let image: RangeSet = input
+                .ranges()
+                .iter()
+                .cloned()
+                .flatten()
+                .map(|v| map.apply(v))
+                .map(|v| v..v + 1)
+                .collect();
+            prop_assert_eq!(map.apply_ranges(&input), image);
+        }
     }
 }
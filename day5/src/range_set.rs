@@ -0,0 +1,184 @@
+use std::ops::Range;
+
+use crate::value::Value;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet<T = u64> {
+    // sorted, non-empty, neither overlapping nor touching
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Value> RangeSet<T> {
+    pub fn new() -> RangeSet<T> {
+        RangeSet { ranges: vec![] }
+    }
+
+    fn normalized(mut ranges: Vec<Range<T>>) -> RangeSet<T> {
+        ranges.retain(|r| r.start < r.end);
+        ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = vec![];
+        for r in ranges {
+            match merged.last_mut() {
+                Some(last) if last.end >= r.start => last.end = last.end.max(r.end),
+                _ => merged.push(r),
+            }
+        }
+        RangeSet { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|r| r.contains(&value))
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalized(ranges);
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        Self::normalized(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for r in &self.ranges {
+            let mut start = r.start;
+            while let Some(b) = other.ranges.get(j) {
+                if b.end <= start {
+                    j += 1;
+                    continue;
+                }
+                if b.start >= r.end {
+                    break;
+                }
+                if b.start > start {
+                    ranges.push(start..b.start);
+                }
+                start = b.end;
+                if b.end > r.end {
+                    break;
+                }
+                j += 1;
+            }
+            if start < r.end {
+                ranges.push(start..r.end);
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    // moves every value v to v - from + to, None if a value leaves T
+    pub fn shift(&self, from: T, to: T) -> Option<RangeSet<T>> {
+        let shift = |v: T| {
+            if to >= from {
+                v.checked_add(&(to - from))
+            } else {
+                v.checked_sub(&(from - to))
+            }
+        };
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|r| Some(shift(r.start)?..shift(r.end)?))
+            .collect::<Option<_>>()?;
+        Some(RangeSet { ranges })
+    }
+}
+
+impl<T: Value> From<Range<T>> for RangeSet<T> {
+    fn from(value: Range<T>) -> Self {
+        Self::normalized(vec![value])
+    }
+}
+
+impl<T: Value> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::normalized(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::RangeSet;
+
+    #[test]
+    fn range_set_normalized() {
+        let set: RangeSet = [(10..20), (0..5), (5..7), (15..25), (30..30)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.ranges(), &[(0..7), (10..25)]);
+        assert_eq!(set.min(), Some(0));
+        assert!(set.contains(24) && !set.contains(25) && !set.contains(7));
+
+        let mut set = set;
+        set.insert(7..10);
+        assert_eq!(set.ranges(), &[(0..25)]);
+        assert!(RangeSet::<u64>::new().is_empty());
+    }
+
+    #[test]
+    fn range_set_operations() {
+        let a: RangeSet = [(0..10), (20..30), (40..50)].into_iter().collect();
+        let b: RangeSet = [(5..25), (45..60)].into_iter().collect();
+
+        assert_eq!(a.union(&b).ranges(), &[(0..30), (40..60)]);
+        assert_eq!(a.intersection(&b).ranges(), &[(5..10), (20..25), (45..50)]);
+        assert_eq!(a.difference(&b).ranges(), &[(0..5), (25..30), (40..45)]);
+        assert_eq!(b.difference(&a).ranges(), &[(10..20), (50..60)]);
+        assert_eq!(
+            a.difference(&RangeSet::<u64>::from(0..100)),
+            RangeSet::new()
+        );
+        assert_eq!(
+            RangeSet::<u64>::from(0..100).difference(&a).ranges().len(),
+            3
+        );
+    }
+
+    #[test]
+    fn range_set_shift() {
+        let set: RangeSet = [(10..20), (30..40)].into_iter().collect();
+        assert_eq!(set.shift(10, 15).unwrap().ranges(), &[(15..25), (35..45)]);
+        assert_eq!(set.shift(10, 0).unwrap().ranges(), &[(0..10), (20..30)]);
+        assert_eq!(set.shift(0, u64::MAX - 30), None);
+        assert_eq!(set.shift(20, 0), None);
+    }
+}
@@ -0,0 +1,24 @@
+use std::{
+    any::type_name,
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::Range,
+    str::FromStr,
+};
+
+use num::{PrimInt, Unsigned};
+
+// The integer type an almanac is read into. Ranges are only built through
+// checked_range, so once parsed every mapping fits and the plain arithmetic
+// on its values cannot overflow.
+pub trait Value: PrimInt + Unsigned + FromStr + Debug + Display + Hash {}
+
+impl<T: PrimInt + Unsigned + FromStr + Debug + Display + Hash> Value for T {}
+
+pub fn checked_range<T: Value>(start: T, len: T) -> Option<Range<T>> {
+    Some(start..start.checked_add(&len)?)
+}
+
+pub fn width<T: Value>() -> &'static str {
+    type_name::<T>()
+}
@@ -0,0 +1,143 @@
+use aoc_common::{
+    parse::{number, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Game {
+    time: u64,
+    record: u64,
+}
+
+pub struct Races {
+    games: Vec<Game>,
+    game_no_space: Game,
+}
+
+pub struct Day6;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut lines = input.lines();
+        let times = parse_line(input, lines.next().unwrap_or_default(), "Time:")?;
+        let distances = parse_line(input, lines.next().unwrap_or_default(), "Distance:")?;
+        Ok(Races {
+            games: parse_games(input, &times, &distances)?,
+            game_no_space: parse_game_no_space(input, &times, &distances)?,
+        })
+    }
+
+    fn part1(races: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part1(&races.games).into())
+    }
+
+    fn part2(races: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part2(&races.game_no_space).into())
+    }
+}
+
+impl Game {
+    fn count_above_record(&self) -> u64 {
+        (1..self.time)
+            .map(|i| i * (self.time - i))
+            .filter(|d| d > &self.record)
+            .count() as u64
+    }
+}
+
+fn parse_line<'a>(input: &str, line: &'a str, label: &str) -> Result<Vec<&'a str>, ParseError> {
+    let values = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::new(input, line, format!("'{}'", label)))?;
+    Ok(values.split_whitespace().collect())
+}
+
+fn parse_games(input: &str, times: &[&str], distances: &[&str]) -> Result<Vec<Game>, ParseError> {
+    if let Some(extra) = distances.get(times.len()) {
+        return Err(ParseError::new(input, extra, "the end of the line"));
+    }
+    if let Some(unmatched) = times.get(distances.len()) {
+        return Err(ParseError::new(
+            input,
+            unmatched,
+            "a time with a distance below it",
+        ));
+    }
+
+    times
+        .iter()
+        .zip(distances)
+        .map(|(time, record)| {
+            Ok(Game {
+                time: number(input, time)?,
+                record: number(input, record)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_game_no_space(
+    input: &str,
+    times: &[&str],
+    distances: &[&str],
+) -> Result<Game, ParseError> {
+    let joined = |values: &[&str]| {
+        values.concat().parse().map_err(|_| {
+            let found = values.first().copied().unwrap_or_default();
+            ParseError::new(input, found, "digits that join into one number")
+        })
+    };
+    Ok(Game {
+        time: joined(times)?,
+        record: joined(distances)?,
+    })
+}
+
+fn part1(games: &[Game]) -> u64 {
+    games.iter().map(|g| g.count_above_record()).product()
+}
+
+fn part2(game: &Game) -> u64 {
+    game.count_above_record()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::solution::Solution;
+
+    use crate::{part1, part2, Day6};
+
+    #[test]
+    fn test_part_1() {
+        let races = Day6::parse("Time:      7  15   30\nDistance:  9  40  200").unwrap();
+        assert_eq!(part1(&races.games), 288);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let races = Day6::parse("Time:      7  15   30\nDistance:  9  40  200").unwrap();
+        assert_eq!(part2(&races.game_no_space), 71503);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day6::parse("Time:      7  15   30\nDistance:  9  4O  200")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column), (2, 15));
+        assert_eq!(e.found, "4O");
+
+        let e = Day6::parse("Time: 7\nDistanc: 9").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.expected, "'Distance:'");
+
+        let e = Day6::parse("Time: 7 15\nDistance: 9").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 9, "15"));
+    }
+}
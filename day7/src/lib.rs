@@ -0,0 +1,392 @@
+use std::fmt::Display;
+
+use aoc_common::{
+    parse::{number, split_once, ParseError},
+    solution::{Answer, Solution},
+};
+use itertools::Itertools;
+
+mod ruleset;
+#[cfg(test)]
+mod test_support;
+
+pub use ruleset::{Card, Ruleset, RulesetError, TieBreak};
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Bid {
+    hand: String,
+    cards: Vec<Card>,
+    amount: u32,
+    tie_break: TieBreak,
+    // the hand type followed by a byte per card in tie-break order, so bids
+    // rank by comparing keys alone
+    key: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+pub struct Bids {
+    pub without_joker: Vec<Bid>,
+    pub with_joker: Vec<Bid>,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Explanation {
+    pub kind: HandType,
+    // the hand with every wild card replaced by the card it counts as
+    pub effective: String,
+    // the position of the first card that differs from the bid ranked just
+    // below, in the order the tie-break compares them, when both have the
+    // same type
+    pub decided_by: Option<usize>,
+}
+
+pub struct Day7;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Bids;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Bids {
+            without_joker: parse_bids(input, &Ruleset::standard())?,
+            with_joker: parse_bids(input, &Ruleset::jokers())?,
+        })
+    }
+
+    fn part1(bids: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(solve(&bids.without_joker).into())
+    }
+
+    fn part2(bids: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(solve(&bids.with_joker).into())
+    }
+}
+
+fn parse_bids(input: &str, ruleset: &Ruleset) -> Result<Vec<Bid>, ParseError> {
+    input
+        .lines()
+        .map(|l| Bid::try_from(l, ruleset).map_err(|e| e.within(input, l)))
+        .collect()
+}
+
+pub fn winnings(input: &str, ruleset: &Ruleset) -> Result<u64, ParseError> {
+    Ok(solve(&parse_bids(input, ruleset)?))
+}
+
+pub fn ranked(bids: &[Bid]) -> Vec<&Bid> {
+    bids.iter().sorted_by_key(|b| b.key).collect()
+}
+
+fn solve(bids: &[Bid]) -> u64 {
+    ranked(bids)
+        .into_iter()
+        .enumerate()
+        .map(|(i, bid)| (i as u64 + 1) * bid.amount as u64)
+        .sum()
+}
+
+impl PartialOrd for Bid {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bid {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl Bid {
+    pub fn hand(&self) -> &str {
+        &self.hand
+    }
+
+    pub fn amount(&self) -> u32 {
+        self.amount
+    }
+
+    fn sort_key(&self) -> u64 {
+        self.tie_break_cards()
+            .iter()
+            .fold(self.kind() as u64, |key, card| key << 8 | card.rank as u64)
+    }
+
+    fn tie_break_cards(&self) -> Vec<Card> {
+        match self.tie_break {
+            TieBreak::InOrder => self.cards.clone(),
+            TieBreak::Sorted => self.cards.iter().sorted().rev().copied().collect(),
+        }
+    }
+
+    // Groups equal cards, with the wild cards joining the longest group (the
+    // strongest one on a tie) or forming one group if there is nothing else.
+    fn sequences(&self) -> Vec<(&Card, usize)> {
+        let mut groups: Vec<(&Card, usize)> = self
+            .cards
+            .iter()
+            .filter(|c| !c.wild)
+            .sorted()
+            .group_by(|c| *c)
+            .into_iter()
+            .map(|(card, group)| (card, group.count()))
+            .collect();
+        groups.sort_by_key(|g| g.1);
+
+        let wild = self.cards.iter().filter(|c| c.wild).count();
+        match groups.last_mut() {
+            Some(longest) => longest.1 += wild,
+            None => groups.extend(self.cards.first().map(|c| (c, wild))),
+        }
+
+        groups
+    }
+
+    pub fn kind(&self) -> HandType {
+        let mut sizes = self.sequences().into_iter().map(|(_, n)| n).sorted().rev();
+        let largest = sizes.next().unwrap_or(0);
+        let second = sizes.next().unwrap_or(0);
+
+        match (largest, second) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2..) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    pub fn explain(&self, below: Option<&Bid>) -> Explanation {
+        let longest = self.sequences().last().map(|(card, _)| **card);
+        let wild_as = self
+            .hand
+            .chars()
+            .zip(&self.cards)
+            .find(|(_, card)| Some(**card) == longest)
+            .map(|(c, _)| c);
+        let effective = self
+            .hand
+            .chars()
+            .zip(&self.cards)
+            .map(|(c, card)| if card.wild { wild_as.unwrap_or(c) } else { c })
+            .collect();
+
+        let kind = self.kind();
+        let decided_by = below.filter(|b| b.kind() == kind).and_then(|b| {
+            self.tie_break_cards()
+                .iter()
+                .zip(b.tie_break_cards().iter())
+                .position(|(mine, theirs)| mine != theirs)
+        });
+
+        Explanation {
+            kind,
+            effective,
+            decided_by,
+        }
+    }
+
+    fn try_from(s: &str, ruleset: &Ruleset) -> Result<Self, ParseError> {
+        let (hand, amount) = split_once(s, s, ' ')?;
+        let cards: Vec<Card> = hand
+            .char_indices()
+            .map(|(i, c)| {
+                ruleset
+                    .card(c)
+                    .ok_or_else(|| ParseError::new(s, &hand[i..i + c.len_utf8()], "a card"))
+            })
+            .collect::<Result<_, _>>()?;
+        if cards.len() != ruleset.hand_size() {
+            let expected = format!("{} cards", ruleset.hand_size());
+            return Err(ParseError::new(s, hand, expected));
+        }
+        let amount: u32 = number(s, amount)?;
+        let mut bid = Bid {
+            hand: hand.to_string(),
+            cards,
+            amount,
+            tie_break: ruleset.tie_break(),
+            key: 0,
+        };
+        bid.key = bid.sort_key();
+        Ok(bid)
+    }
+}
+
+impl Display for HandType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            HandType::HighCard => "high card",
+            HandType::OnePair => "one pair",
+            HandType::TwoPair => "two pair",
+            HandType::ThreeOfAKind => "three of a kind",
+            HandType::FullHouse => "full house",
+            HandType::FourOfAKind => "four of a kind",
+            HandType::FiveOfAKind => "five of a kind",
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Ordering;
+
+    use aoc_common::solution::Solution;
+
+    use crate::{ranked, solve, winnings, Bid, Day7, HandType, Ruleset, TieBreak, EXAMPLE};
+
+    #[test]
+    fn test_part_1() {
+        let bids: Vec<Bid> = [
+            "32T3K 765",
+            "T55J5 684",
+            "KK677 28",
+            "KTJJT 220",
+            "QQQJA 483",
+        ]
+        .iter()
+        .map(|l| Bid::try_from(l, &Ruleset::standard()).unwrap())
+        .collect();
+        assert_eq!(solve(&bids), 6440);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let bids: Vec<Bid> = [
+            "32T3K 765",
+            "T55J5 684",
+            "KK677 28",
+            "KTJJT 220",
+            "QQQJA 483",
+        ]
+        .iter()
+        .map(|l| Bid::try_from(l, &Ruleset::jokers()).unwrap())
+        .collect();
+        assert_eq!(solve(&bids), 5905);
+    }
+
+    #[test]
+    fn test_joker_type() {
+        let bid = Bid::try_from("KJJJK 500", &Ruleset::jokers()).unwrap();
+        assert_eq!(bid.kind(), HandType::FiveOfAKind);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day7::parse("32T3K 765\nT55X5 684").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.found, "X");
+
+        let e = Day7::parse("32T3K 765\nT55J 684").err().unwrap();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (2, 1, "5 cards"));
+
+        let e = Day7::parse("32T3K lots").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 7, "lots"));
+    }
+
+    #[test]
+    fn test_rulesets() {
+        let kind = |hand: &str, ruleset: &Ruleset| {
+            Bid::try_from(&format!("{} 1", hand), ruleset)
+                .unwrap()
+                .kind()
+        };
+
+        let wild = Ruleset::new("23456789TJQKA", "JQ", 5, TieBreak::InOrder).unwrap();
+        assert_eq!(kind("JQ2A3", &wild), HandType::ThreeOfAKind);
+        assert_eq!(kind("JQJQJ", &wild), HandType::FiveOfAKind);
+        assert_eq!(kind("2233Q", &wild), HandType::FullHouse);
+
+        let six = Ruleset::new("23456789TJQKA", "J", 6, TieBreak::InOrder).unwrap();
+        assert_eq!(kind("222333", &six), HandType::FullHouse);
+        assert_eq!(kind("2JJ333", &six), HandType::FiveOfAKind);
+        assert_eq!(kind("223344", &six), HandType::TwoPair);
+        assert!(Bid::try_from("23456 1", &six).is_err());
+
+        // aces low
+        let aces_low = Ruleset::new("A23456789TJQK", "", 5, TieBreak::InOrder).unwrap();
+        assert_eq!(winnings("A2345 1\n23456 2", &aces_low), Ok(1 + 2 * 2));
+        assert_eq!(
+            winnings("A2345 1\n23456 2", &Ruleset::standard()),
+            Ok(2 + 2)
+        );
+
+        let sorted = Ruleset::new("23456789TJQKA", "", 5, TieBreak::Sorted).unwrap();
+        assert_eq!(winnings("A2345 1\n2345A 2", &sorted), Ok(1 + 2 * 2));
+        assert_eq!(winnings("A2345 1\n2345K 2", &sorted), Ok(2 + 2));
+    }
+
+    #[test]
+    fn test_explain() {
+        let bids = Day7::parse(EXAMPLE).unwrap();
+        let jokers = ranked(&bids.with_joker);
+        let hands: Vec<_> = jokers.iter().map(|b| b.hand()).collect();
+        assert_eq!(hands, vec!["32T3K", "KK677", "T55J5", "QQQJA", "KTJJT"]);
+
+        let explained: Vec<_> = jokers
+            .iter()
+            .enumerate()
+            .map(|(i, bid)| bid.explain(i.checked_sub(1).map(|j| jokers[j])))
+            .collect();
+        assert_eq!(explained[0].kind, HandType::OnePair);
+        assert_eq!(explained[0].decided_by, None);
+        assert_eq!(explained[1].decided_by, None);
+        assert_eq!(explained[2].effective, "T5555");
+        assert_eq!(explained[3].effective, "QQQQA");
+        assert_eq!(explained[3].decided_by, Some(0));
+        assert_eq!(explained[4].effective, "KTTTT");
+        assert_eq!(explained[4].kind.to_string(), "four of a kind");
+
+        let standard = ranked(&bids.without_joker);
+        let kk = standard[2].explain(Some(standard[1]));
+        assert_eq!((standard[2].hand(), kk.decided_by), ("KK677", Some(1)));
+        assert_eq!(kk.effective, "KK677");
+
+        let all_jokers = Bid::try_from("JJJJJ 1", &Ruleset::jokers()).unwrap();
+        assert_eq!(all_jokers.explain(None).effective, "JJJJJ");
+    }
+
+    // the ranking before sort keys, comparing types and then cards
+    fn reference_cmp(a: &Bid, b: &Bid) -> Ordering {
+        a.kind()
+            .cmp(&b.kind())
+            .then_with(|| a.tie_break_cards().cmp(&b.tie_break_cards()))
+    }
+
+    #[test]
+    fn test_sort_keys() {
+        let bids = Day7::parse(EXAMPLE).unwrap();
+        let sorted = Ruleset::new("23456789TJQKA", "J", 5, TieBreak::Sorted).unwrap();
+        let hands = [
+            "JJJJJ", "AAAAA", "2345J", "J2345", "AKQJT", "22JJ3", "AA2KK",
+        ];
+        let extra: Vec<Bid> = hands
+            .iter()
+            .map(|h| Bid::try_from(&format!("{} 1", h), &sorted).unwrap())
+            .collect();
+
+        for bids in [&bids.without_joker, &bids.with_joker, &extra] {
+            for a in bids.iter() {
+                for b in bids.iter() {
+                    assert_eq!(a.cmp(b), reference_cmp(a, b), "{} {}", a.hand, b.hand);
+                }
+            }
+        }
+    }
+}
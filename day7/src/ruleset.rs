@@ -0,0 +1,156 @@
+use std::fmt::Display;
+
+// every card takes a byte of a bid's sort key, below the hand type
+pub const MAX_HAND_SIZE: usize = 7;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Card {
+    // position in the ruleset's order, higher is stronger
+    pub rank: u8,
+    pub wild: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TieBreak {
+    // compare the cards one by one in the order they were dealt
+    InOrder,
+    // compare the cards from the strongest to the weakest
+    Sorted,
+}
+
+#[derive(Clone, Debug)]
+pub struct Ruleset {
+    // the card alphabet from the weakest to the strongest card
+    order: Vec<char>,
+    wild: Vec<char>,
+    hand_size: usize,
+    tie_break: TieBreak,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum RulesetError {
+    TooManyCards(usize),
+    RepeatedCard(char),
+    UnknownWildCard(char),
+    EmptyHand,
+    HandTooLarge(usize),
+}
+
+impl Ruleset {
+    pub fn new(
+        order: &str,
+        wild: &str,
+        hand_size: usize,
+        tie_break: TieBreak,
+    ) -> Result<Ruleset, RulesetError> {
+        let order: Vec<char> = order.chars().collect();
+        if order.len() > u8::MAX as usize {
+            return Err(RulesetError::TooManyCards(order.len()));
+        }
+        if let Some((_, &c)) = order
+            .iter()
+            .enumerate()
+            .find(|(i, c)| order[..*i].contains(c))
+        {
+            return Err(RulesetError::RepeatedCard(c));
+        }
+        if let Some(c) = wild.chars().find(|c| !order.contains(c)) {
+            return Err(RulesetError::UnknownWildCard(c));
+        }
+        if hand_size == 0 {
+            return Err(RulesetError::EmptyHand);
+        }
+        if hand_size > MAX_HAND_SIZE {
+            return Err(RulesetError::HandTooLarge(hand_size));
+        }
+
+        Ok(Ruleset {
+            order,
+            wild: wild.chars().collect(),
+            hand_size,
+            tie_break,
+        })
+    }
+
+    pub fn standard() -> Ruleset {
+        Ruleset::new("23456789TJQKA", "", 5, TieBreak::InOrder).unwrap()
+    }
+
+    // J is a joker that counts as whatever makes the hand strongest, but is
+    // the weakest card on its own
+    pub fn jokers() -> Ruleset {
+        Ruleset::new("J23456789TQKA", "J", 5, TieBreak::InOrder).unwrap()
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
+
+    pub fn card(&self, c: char) -> Option<Card> {
+        let rank = self.order.iter().position(|o| *o == c)?;
+        Some(Card {
+            rank: rank as u8,
+            wild: self.wild.contains(&c),
+        })
+    }
+
+    pub fn symbol(&self, card: Card) -> char {
+        self.order[card.rank as usize]
+    }
+}
+
+impl Display for RulesetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesetError::TooManyCards(n) => {
+                write!(f, "{} cards, at most {} are supported", n, u8::MAX)
+            }
+            RulesetError::RepeatedCard(c) => write!(f, "card {} appears twice in the order", c),
+            RulesetError::UnknownWildCard(c) => {
+                write!(f, "wild card {} is not in the order", c)
+            }
+            RulesetError::EmptyHand => f.write_str("hands need at least one card"),
+            RulesetError::HandTooLarge(n) => write!(
+                f,
+                "hands of {} cards, at most {} are supported",
+                n, MAX_HAND_SIZE
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ruleset, RulesetError, TieBreak};
+
+    #[test]
+    fn test_ruleset() {
+        let jokers = Ruleset::jokers();
+        let joker = jokers.card('J').unwrap();
+        assert!(joker.wild && joker < jokers.card('2').unwrap());
+        assert!(!Ruleset::standard().card('J').unwrap().wild);
+        assert_eq!(jokers.symbol(joker), 'J');
+        assert_eq!(jokers.card('X'), None);
+
+        assert_eq!(
+            Ruleset::new("AKQA", "", 5, TieBreak::InOrder).err(),
+            Some(RulesetError::RepeatedCard('A'))
+        );
+        assert_eq!(
+            Ruleset::new("AKQ", "J", 5, TieBreak::InOrder).err(),
+            Some(RulesetError::UnknownWildCard('J'))
+        );
+        assert_eq!(
+            Ruleset::new("AKQ", "", 0, TieBreak::Sorted).err(),
+            Some(RulesetError::EmptyHand)
+        );
+        assert_eq!(
+            Ruleset::new("AKQ", "", 8, TieBreak::Sorted).err(),
+            Some(RulesetError::HandTooLarge(8))
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use crate::{Bid, HandType, Ruleset};
+
+const CARDS: &str = "23456789TJQKA";
+
+// Every five card hand, as indices into CARDS.
+pub fn all_hands() -> impl Iterator<Item = [usize; 5]> {
+    let n = CARDS.len();
+    (0..n.pow(5)).map(move |i| {
+        let mut hand = [0; 5];
+        for (j, card) in hand.iter_mut().enumerate() {
+            *card = i / n.pow(4 - j as u32) % n;
+        }
+        hand
+    })
+}
+
+fn classify(hand: &[usize]) -> HandType {
+    let mut counts = [0; 13];
+    for &card in hand {
+        counts[card] += 1;
+    }
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    match counts[..5] {
+        [5, 0, 0, 0, 0] => HandType::FiveOfAKind,
+        [4, 1, 0, 0, 0] => HandType::FourOfAKind,
+        [3, 2, 0, 0, 0] => HandType::FullHouse,
+        [3, 1, 1, 0, 0] => HandType::ThreeOfAKind,
+        [2, 2, 1, 0, 0] => HandType::TwoPair,
+        [2, 1, 1, 1, 0] => HandType::OnePair,
+        [1, 1, 1, 1, 1] => HandType::HighCard,
+        _ => unreachable!("{:?} is not a five card hand", counts),
+    }
+}
+
+// Tries every card that is not wild for every wild card in turn.
+fn best_kind(hand: &mut [usize], wild: &[usize], substitutes: &[usize]) -> HandType {
+    let Some((&i, rest)) = wild.split_first() else {
+        return classify(hand);
+    };
+    let original = hand[i];
+    let mut best = HandType::HighCard;
+    for &card in substitutes {
+        hand[i] = card;
+        best = best.max(best_kind(hand, rest, substitutes));
+        if best == HandType::FiveOfAKind {
+            break;
+        }
+    }
+    hand[i] = original;
+    best
+}
+
+pub struct Reference<'a> {
+    ruleset: &'a Ruleset,
+    // the type only depends on which cards a hand holds, not on their order
+    known: HashMap<[usize; 5], HandType>,
+}
+
+impl<'a> Reference<'a> {
+    pub fn new(ruleset: &'a Ruleset) -> Reference<'a> {
+        Reference {
+            ruleset,
+            known: HashMap::new(),
+        }
+    }
+
+    fn is_wild(&self, card: usize) -> bool {
+        self.ruleset
+            .card(CARDS.as_bytes()[card] as char)
+            .unwrap()
+            .wild
+    }
+
+    pub fn kind(&mut self, hand: &[usize; 5]) -> HandType {
+        let mut sorted = *hand;
+        sorted.sort_unstable();
+        if let Some(kind) = self.known.get(&sorted) {
+            return *kind;
+        }
+
+        let wild: Vec<usize> = (0..5).filter(|&i| self.is_wild(sorted[i])).collect();
+        let substitutes: Vec<usize> = (0..CARDS.len()).filter(|&c| !self.is_wild(c)).collect();
+        let kind = best_kind(&mut sorted.clone(), &wild, &substitutes);
+        self.known.insert(sorted, kind);
+        kind
+    }
+}
+
+pub fn bid(hand: &[usize; 5], ruleset: &Ruleset) -> Bid {
+    let hand: String = hand.iter().map(|&c| &CARDS[c..c + 1]).collect();
+    Bid::try_from(&format!("{} 1", hand), ruleset).unwrap()
+}
+
+fn check_all_hands(ruleset: &Ruleset, expected: [usize; 7]) {
+    let mut reference = Reference::new(ruleset);
+    let mut counts = [0; 7];
+    for hand in all_hands() {
+        let kind = bid(&hand, ruleset).kind();
+        assert_eq!(kind, reference.kind(&hand), "{:?}", hand);
+        counts[kind as usize] += 1;
+    }
+    assert_eq!(counts, expected);
+}
+
+// counts from high card to five of a kind
+#[test]
+fn test_all_hands_standard() {
+    let expected = [154440, 171600, 25740, 17160, 1560, 780, 13];
+    check_all_hands(&Ruleset::standard(), expected);
+}
+
+#[test]
+fn test_all_hands_jokers() {
+    let expected = [95040, 178200, 19800, 66000, 3300, 8580, 373];
+    check_all_hands(&Ruleset::jokers(), expected);
+}
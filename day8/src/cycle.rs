@@ -0,0 +1,400 @@
+use num::{integer::ExtendedGcd, Integer};
+
+use crate::{Network, NetworkError};
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Cycle {
+    // the node and instruction index at which the walk first repeats itself
+    pub state: (usize, usize),
+    // the step at which that state was first reached, the cycle starts there
+    pub start: u64,
+    pub length: u64,
+    // steps before the cycle at which the walk is on an end node, in order
+    pub lead_in: Vec<u64>,
+    // offsets from the start of the cycle at which the walk is on an end node,
+    // in order
+    pub hits: Vec<u64>,
+}
+
+impl Cycle {
+    pub fn analyse(network: &Network, from: usize, ends: &[bool]) -> Result<Cycle, NetworkError> {
+        let directions = network.directions();
+        if directions.is_empty() {
+            return Err(NetworkError::NoPath);
+        }
+
+        // the step at which each (node, instruction) state was first reached
+        let mut seen: Vec<Option<u64>> = vec![None; network.nodes().len() * directions.len()];
+        let mut hits = vec![];
+        let mut node = from;
+        let mut step: u64 = 0;
+
+        loop {
+            let i = (step % directions.len() as u64) as usize;
+            let state = node * directions.len() + i;
+            if let Some(start) = seen[state] {
+                let (lead_in, hits): (Vec<u64>, Vec<u64>) =
+                    hits.into_iter().partition(|&s| s < start);
+                return Ok(Cycle {
+                    state: (node, i),
+                    start,
+                    length: step - start,
+                    lead_in: lead_in.into_iter().filter(|&s| s > 0).collect(),
+                    hits: hits.into_iter().map(|s| s - start).collect(),
+                });
+            }
+            seen[state] = Some(step);
+            if ends[node] {
+                hits.push(step);
+            }
+
+            node = network.nodes()[node].next(directions[i]);
+            step += 1;
+        }
+    }
+
+    pub fn ends_at(&self, step: u64) -> bool {
+        step > 0
+            && (self.lead_in.binary_search(&step).is_ok()
+                || (step >= self.start
+                    && self
+                        .hits
+                        .binary_search(&((step - self.start) % self.length))
+                        .is_ok()))
+    }
+}
+
+// x = a1 (mod m1) and x = a2 (mod m2) as a single congruence, if there is
+// one; the moduli need not be coprime.
+fn crt(
+    (a1, m1): (i128, i128),
+    (a2, m2): (i128, i128),
+) -> Result<Option<(i128, i128)>, NetworkError> {
+    let ExtendedGcd { gcd, x, .. } = m1.extended_gcd(&m2);
+    if (a2 - a1) % gcd != 0 {
+        return Ok(None);
+    }
+    let lcm = (m1 / gcd)
+        .checked_mul(m2)
+        .ok_or(NetworkError::TooManySteps)?;
+    let k = ((a2 - a1) / gcd)
+        .checked_mul(x)
+        .ok_or(NetworkError::TooManySteps)?
+        .mod_floor(&(m2 / gcd));
+    let a = m1
+        .checked_mul(k)
+        .and_then(|a| a.checked_add(a1))
+        .ok_or(NetworkError::TooManySteps)?;
+    Ok(Some((a.mod_floor(&lcm), lcm)))
+}
+
+// Up to this many combinations of hits, a congruence for every combination
+// fits in memory and is quick to solve.
+const MAX_SYSTEMS: usize = 1 << 20;
+
+// Past that, stepping through the hits of one walk finds early common steps,
+// but with no common step it would run until the walks repeat together, so it
+// gives up after this many tries.
+const MAX_CANDIDATES: u64 = 1 << 24;
+
+// The first step inside every cycle at which all walks are on an end node,
+// one congruence per combination of hits.
+fn solve_systems(cycles: &[Cycle]) -> Result<Option<u64>, NetworkError> {
+    let mut systems: Vec<(i128, i128)> = vec![(0, 1)];
+    for cycle in cycles {
+        let mut next = vec![];
+        for &system in &systems {
+            for &hit in &cycle.hits {
+                let step = (cycle.start + hit) as i128;
+                next.extend(crt(system, (step, cycle.length as i128))?);
+            }
+        }
+        next.sort();
+        next.dedup();
+        systems = next;
+        if systems.is_empty() {
+            break;
+        }
+    }
+
+    let earliest = cycles.iter().map(|c| c.start).max().unwrap_or(0).max(1) as i128;
+    systems
+        .into_iter()
+        .map(|(a, m)| {
+            Integer::div_ceil(&(earliest - a), &m)
+                .max(0)
+                .checked_mul(m)
+                .and_then(|s| s.checked_add(a))
+                .ok_or(NetworkError::TooManySteps)
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .min()
+        .map(|step| u64::try_from(step).map_err(|_| NetworkError::TooManySteps))
+        .transpose()
+}
+
+// The same, trying the hits of the walk with the longest cycle in order until
+// the walks have all gone round their cycles together or `budget` hits have
+// been tried.
+fn step_through(cycles: &[Cycle], budget: u64) -> Result<Option<u64>, NetworkError> {
+    let Some(longest) = cycles.iter().max_by_key(|c| c.length) else {
+        return Ok(None);
+    };
+    if longest.hits.is_empty() {
+        return Ok(None);
+    }
+    let lcm = cycles.iter().try_fold(1u64, |lcm, c| {
+        (lcm / lcm.gcd(&c.length)).checked_mul(c.length)
+    });
+    // with no lcm in range, only the budget stops the search
+    let limit = lcm.and_then(|lcm| {
+        let start = cycles.iter().map(|c| c.start).max().unwrap_or(0);
+        start.max(1).checked_add(lcm)
+    });
+
+    let mut lap = longest.start;
+    let mut tries = 0;
+    loop {
+        for &hit in &longest.hits {
+            if tries == budget {
+                return Err(NetworkError::TooManyCandidates);
+            }
+            tries += 1;
+            let step = lap.checked_add(hit).ok_or(NetworkError::TooManySteps)?;
+            if limit.is_some_and(|limit| step >= limit) {
+                return Ok(None);
+            }
+            if cycles.iter().all(|c| c.ends_at(step)) {
+                return Ok(Some(step));
+            }
+        }
+        lap = lap
+            .checked_add(longest.length)
+            .ok_or(NetworkError::TooManySteps)?;
+    }
+}
+
+// The first step, from 1 on, at which every walk is on an end node.
+pub fn first_common_step(cycles: &[Cycle]) -> Result<u64, NetworkError> {
+    // a step inside some walk's lead-in has to be one of its lead-in hits
+    let lead_in = cycles
+        .iter()
+        .flat_map(|c| &c.lead_in)
+        .copied()
+        .filter(|&step| cycles.iter().all(|c| c.ends_at(step)))
+        .min();
+
+    // otherwise every walk is inside its cycle, on one of its hits
+    let systems = cycles
+        .iter()
+        .try_fold(1usize, |n, c| n.checked_mul(c.hits.len().max(1)));
+    let cyclic = if systems.is_some_and(|n| n <= MAX_SYSTEMS) {
+        solve_systems(cycles)?
+    } else {
+        step_through(cycles, MAX_CANDIDATES)?
+    };
+
+    lead_in
+        .into_iter()
+        .chain(cyclic)
+        .min()
+        .ok_or(NetworkError::NoCommonStep)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::solution::Solution;
+
+    use crate::{Day8, NetworkError};
+
+    use super::{first_common_step, solve_systems, step_through, Cycle, MAX_CANDIDATES};
+
+    fn cycles(lines: &[&str]) -> Vec<Cycle> {
+        let network = Day8::parse(&lines.join("\n")).unwrap();
+        let nodes = network.nodes();
+        let ends: Vec<bool> = nodes.iter().map(|n| n.name.ends_with('Z')).collect();
+        (0..nodes.len())
+            .filter(|&i| nodes[i].name.ends_with('A'))
+            .map(|i| Cycle::analyse(&network, i, &ends))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_cycle() {
+        let found = cycles(&[
+            "LR",
+            "",
+            "11A = (11B, XXX)",
+            "11B = (XXX, 11Z)",
+            "11Z = (11B, XXX)",
+            "XXX = (XXX, XXX)",
+        ]);
+        assert_eq!(
+            found,
+            vec![Cycle {
+                state: (1, 1),
+                start: 1,
+                length: 2,
+                lead_in: vec![],
+                hits: vec![1],
+            }]
+        );
+        assert!(found[0].ends_at(2) && found[0].ends_at(4) && !found[0].ends_at(3));
+    }
+
+    #[test]
+    fn test_first_common_step() {
+        // Z after 2 of 5 and after 1 of 3 steps, where the lcm would say 15
+        let rings = [
+            "L",
+            "",
+            "1A = (1B, 1A)",
+            "1B = (1Z, 1B)",
+            "1Z = (1C, 1Z)",
+            "1C = (1D, 1C)",
+            "1D = (1A, 1D)",
+            "2A = (2Z, 2A)",
+            "2Z = (2B, 2Z)",
+            "2B = (2A, 2B)",
+        ];
+        assert_eq!(first_common_step(&cycles(&rings)).unwrap(), 7);
+
+        // odd steps against even ones
+        let apart = [
+            "L",
+            "",
+            "1A = (1Z, 1A)",
+            "1Z = (1B, 1Z)",
+            "1B = (1C, 1B)",
+            "1C = (1A, 1C)",
+            "2A = (2B, 2A)",
+            "2B = (2Z, 2B)",
+            "2Z = (2B, 2Z)",
+        ];
+        assert!(matches!(
+            first_common_step(&cycles(&apart)),
+            Err(NetworkError::NoCommonStep)
+        ));
+
+        // the end node is only passed once, before the cycle
+        let lead_in = [
+            "L",
+            "",
+            "1A = (1Z, 1A)",
+            "1Z = (1B, 1Z)",
+            "1B = (1C, 1B)",
+            "1C = (1B, 1C)",
+            "2A = (2Z, 2A)",
+            "2Z = (2A, 2Z)",
+        ];
+        let found = cycles(&lead_in);
+        assert_eq!((found[0].lead_in.as_slice(), found[0].start), (&[1][..], 2));
+        assert_eq!(first_common_step(&found).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_step_through() {
+        let rings = [
+            "L",
+            "",
+            "1A = (1B, 1A)",
+            "1B = (1Z, 1B)",
+            "1Z = (1C, 1Z)",
+            "1C = (1D, 1C)",
+            "1D = (1A, 1D)",
+            "2A = (2Z, 2A)",
+            "2Z = (2B, 2Z)",
+            "2B = (2A, 2B)",
+            "3A = (3B, 3A)",
+            "3B = (3Z, 3B)",
+            "3Z = (3C, 3Z)",
+            "3C = (3A, 3C)",
+        ];
+        // the rings of 5 and 3 meet at 7 mod 15, the ring of 4 only on 22
+        for (lines, expected) in [(&rings[..10], 7), (&rings[..], 22)] {
+            let found = cycles(lines);
+            assert_eq!(
+                step_through(&found, MAX_CANDIDATES).unwrap(),
+                Some(expected)
+            );
+            assert_eq!(solve_systems(&found).unwrap(), Some(expected));
+        }
+        // 22 is the fifth hit of the ring of 5
+        let found = cycles(&rings);
+        assert_eq!(step_through(&found, 5).unwrap(), Some(22));
+        assert!(matches!(
+            step_through(&found, 4),
+            Err(NetworkError::TooManyCandidates)
+        ));
+    }
+
+    // A network of "L" with a ring of every length, starting on ..A and
+    // ending on ..Z at the offsets `is_end` picks.
+    fn rings(lengths: &[usize], is_end: impl Fn(usize, usize) -> bool) -> String {
+        let mut lines = vec!["L".to_string(), String::new()];
+        for (ring, &length) in lengths.iter().enumerate() {
+            let name = |i: usize| match i % length {
+                0 => format!("{}A", ring),
+                i if is_end(ring, i) => format!("{}N{}Z", ring, i),
+                i => format!("{}N{}", ring, i),
+            };
+            for i in 0..length {
+                lines.push(format!("{} = ({}, {})", name(i), name(i + 1), name(i)));
+            }
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn test_many_hits() {
+        // rings of 61 to 65 nodes, all of them ends but the start, are too
+        // many combinations of hits to solve one by one
+        let network = Day8::parse(&rings(&[61, 62, 63, 64, 65], |_, _| true)).unwrap();
+        assert_eq!(network.nodes().len(), 315);
+        assert_eq!(Day8::part2(&network).unwrap(), 1u64.into());
+    }
+
+    #[test]
+    fn test_many_hits_apart() {
+        // rings of twice a prime with five ends each, on odd offsets in the
+        // first ring and on even ones in the others, never meet; 5^7
+        // combinations are still few enough to rule out one by one
+        let lengths: Vec<usize> = [31, 37, 41, 43, 47, 53, 59].iter().map(|p| 2 * p).collect();
+        let ends = |ring: usize, i: usize| i <= 10 && i % 2 == usize::from(ring == 0);
+        let network = Day8::parse(&rings(&lengths, ends)).unwrap();
+        assert!(matches!(
+            Day8::part2(&network),
+            Err(NetworkError::NoCommonStep)
+        ));
+
+        // stepping through the laps of the longest ring would take too long
+        let found = cycles(&rings(&lengths, ends).lines().collect::<Vec<_>>());
+        assert!(found.iter().all(|c| c.hits.len() == 5));
+        assert!(matches!(
+            step_through(&found, 1000),
+            Err(NetworkError::TooManyCandidates)
+        ));
+    }
+
+    #[test]
+    fn test_too_many_steps() {
+        // one step short of the product of the first 30 primes, past i128
+        let primes = (2u64..).filter(|&n| (2..n).all(|d| n % d != 0));
+        let found: Vec<Cycle> = primes
+            .take(30)
+            .map(|p| Cycle {
+                state: (0, 0),
+                start: 0,
+                length: p,
+                lead_in: vec![],
+                hits: vec![p - 1],
+            })
+            .collect();
+        assert!(matches!(
+            first_common_step(&found),
+            Err(NetworkError::TooManySteps)
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+
+use crate::{Direction, Network};
+
+impl Network {
+    // The nodes and edges visited when walking from `from` until the walk
+    // repeats itself.
+    fn walked(&self, from: usize) -> (HashSet<usize>, HashSet<(usize, Direction)>) {
+        let mut nodes = HashSet::from([from]);
+        let mut edges = HashSet::new();
+        let mut seen = HashSet::new();
+        let mut node = from;
+
+        for (i, &direction) in self.directions().iter().enumerate().cycle() {
+            if !seen.insert((node, i)) {
+                break;
+            }
+            edges.insert((node, direction));
+            node = self.nodes()[node].next(direction);
+            nodes.insert(node);
+        }
+
+        (nodes, edges)
+    }
+
+    // Graphviz DOT with start (..A) nodes as green boxes, end (..Z) nodes as
+    // red double circles and, given a start, the path walked from it in blue.
+    pub fn dot(&self, highlight_from: Option<usize>) -> String {
+        let (walked_nodes, walked_edges) = highlight_from
+            .map(|from| self.walked(from))
+            .unwrap_or_default();
+
+        let mut dot = String::from("digraph network {\n");
+        for (i, node) in self.nodes().iter().enumerate() {
+            let mut attributes = vec![];
+            if node.name.ends_with('A') {
+                attributes.push("shape=box, style=filled, fillcolor=palegreen");
+            } else if node.name.ends_with('Z') {
+                attributes.push("shape=doublecircle, style=filled, fillcolor=lightcoral");
+            }
+            if walked_nodes.contains(&i) {
+                attributes.push("color=blue, penwidth=2");
+            }
+            if !attributes.is_empty() {
+                dot += &format!("    \"{}\" [{}];\n", node.name, attributes.join(", "));
+            }
+        }
+
+        for (i, node) in self.nodes().iter().enumerate() {
+            for (direction, label) in [(Direction::Left, "L"), (Direction::Right, "R")] {
+                let target = &self.nodes()[node.next(direction)].name;
+                let walked = if walked_edges.contains(&(i, direction)) {
+                    ", color=blue, fontcolor=blue, penwidth=2"
+                } else {
+                    ""
+                };
+                dot += &format!(
+                    "    \"{}\" -> \"{}\" [label=\"{}\"{}];\n",
+                    node.name, target, label, walked
+                );
+            }
+        }
+        dot += "}\n";
+
+        dot
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::solution::Solution;
+
+    use crate::Day8;
+
+    #[test]
+    fn test_dot() {
+        let network =
+            Day8::parse("L\n\n11A = (11Z, XXX)\n11Z = (11A, XXX)\nXXX = (XXX, XXX)").unwrap();
+
+        let dot = network.dot(None);
+        assert!(dot.starts_with("digraph network {\n"));
+        assert!(dot.contains("\"11A\" [shape=box, style=filled, fillcolor=palegreen];"));
+        assert!(dot.contains("\"11Z\" [shape=doublecircle,"));
+        assert!(dot.contains("    \"11A\" -> \"11Z\" [label=\"L\"];\n"));
+        assert!(dot.contains("    \"11A\" -> \"XXX\" [label=\"R\"];\n"));
+        assert_eq!(dot.lines().count(), 2 + 2 + 6);
+        assert!(!dot.contains("blue"));
+
+        let dot = network.dot(network.find("11A"));
+        assert!(dot.contains("\"11A\" -> \"11Z\" [label=\"L\", color=blue"));
+        assert!(dot.contains("\"11Z\" -> \"11A\" [label=\"L\", color=blue"));
+        assert!(dot.contains("    \"11A\" -> \"XXX\" [label=\"R\"];\n"));
+        assert!(!dot.contains("\"XXX\" [color=blue"));
+    }
+}
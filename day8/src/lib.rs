@@ -0,0 +1,329 @@
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_common::{
+    parse::{split_once, ParseError},
+    solution::{Answer, Solution},
+};
+
+mod cycle;
+mod dot;
+
+pub use cycle::{first_common_step, Cycle};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+pub struct Node {
+    pub name: String,
+    // indices of the left and right neighbours in the network's nodes
+    pub next: (usize, usize),
+}
+
+pub struct Network {
+    directions: Vec<Direction>,
+    nodes: Vec<Node>,
+    index: HashMap<String, usize>,
+}
+
+#[derive(Debug)]
+pub enum NetworkError {
+    Parse(ParseError),
+    NoPath,
+    NeverEnds(String),
+    NoCommonStep,
+    TooManySteps,
+    TooManyCandidates,
+}
+
+pub struct Day8;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Error = NetworkError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut lines = input.lines();
+        let first = lines.next().unwrap_or_default();
+        let directions = first
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                _ => Err(ParseError::new(
+                    input,
+                    &first[i..i + c.len_utf8()],
+                    "'L' or 'R'",
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        let (nodes, index) = parse_nodes(input, lines.filter(|l| !l.is_empty()))?;
+        Ok(Network {
+            directions,
+            nodes,
+            index,
+        })
+    }
+
+    fn part1(network: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part_1(network)?.into())
+    }
+
+    fn part2(network: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part_2(network)?.into())
+    }
+}
+
+impl Display for NetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkError::Parse(e) => Display::fmt(e, f),
+            NetworkError::NoPath => f.write_str("no path to the end node"),
+            NetworkError::NeverEnds(from) => {
+                write!(f, "the walk from {} never reaches an end node", from)
+            }
+            NetworkError::NoCommonStep => {
+                f.write_str("the walks are never on end nodes at the same step")
+            }
+            NetworkError::TooManySteps => {
+                f.write_str("the walks meet on end nodes after more than 2^64 steps")
+            }
+            NetworkError::TooManyCandidates => {
+                f.write_str("too many combinations of end nodes to find a common step")
+            }
+        }
+    }
+}
+
+impl From<ParseError> for NetworkError {
+    fn from(value: ParseError) -> Self {
+        NetworkError::Parse(value)
+    }
+}
+
+impl Node {
+    pub fn next(&self, direction: Direction) -> usize {
+        match direction {
+            Direction::Left => self.next.0,
+            Direction::Right => self.next.1,
+        }
+    }
+}
+
+impl Network {
+    pub fn directions(&self) -> &[Direction] {
+        &self.directions
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+}
+
+fn node_name<'a>(input: &str, s: &'a str) -> Result<&'a str, ParseError> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(ParseError::new(input, s, "a node name"));
+    }
+    Ok(s)
+}
+
+fn parse_nodes<'a>(
+    input: &str,
+    lines: impl Iterator<Item = &'a str>,
+) -> Result<(Vec<Node>, HashMap<String, usize>), ParseError> {
+    let mut index = HashMap::new();
+    let mut edges = vec![];
+
+    for line in lines {
+        let (from, to) = split_once(input, line, '=')?;
+        let from = node_name(input, from.trim())?;
+        let to = to.trim();
+        let to = to
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+            .ok_or_else(|| ParseError::new(input, to, "'(left, right)'"))?;
+        let (left, right) = split_once(input, to, ',')?;
+        let to = (
+            node_name(input, left.trim())?,
+            node_name(input, right.trim())?,
+        );
+
+        if index.insert(from.to_string(), edges.len()).is_some() {
+            return Err(ParseError::new(
+                input,
+                from,
+                "a node that is not defined yet",
+            ));
+        }
+        edges.push((from, to));
+    }
+
+    // names are only resolved once every node is known
+    let resolve = |name: &str| {
+        index
+            .get(name)
+            .copied()
+            .ok_or_else(|| ParseError::new(input, name, "a node defined in the network"))
+    };
+    let nodes = edges
+        .iter()
+        .map(|(name, (left, right))| {
+            Ok(Node {
+                name: name.to_string(),
+                next: (resolve(left)?, resolve(right)?),
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((nodes, index))
+}
+
+fn walks(
+    network: &Network,
+    starts: &[usize],
+    is_end: impl Fn(&str) -> bool,
+) -> Result<u64, NetworkError> {
+    let ends: Vec<bool> = network.nodes.iter().map(|n| is_end(&n.name)).collect();
+    let cycles = starts
+        .iter()
+        .map(|&from| {
+            let cycle = Cycle::analyse(network, from, &ends)?;
+            if cycle.lead_in.is_empty() && cycle.hits.is_empty() {
+                let name = network.nodes[from].name.clone();
+                return Err(NetworkError::NeverEnds(name));
+            }
+            Ok(cycle)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if cycles.is_empty() {
+        return Err(NetworkError::NoPath);
+    }
+    first_common_step(&cycles)
+}
+
+fn part_1(network: &Network) -> Result<u64, NetworkError> {
+    let start = network.find("AAA").ok_or(NetworkError::NoPath)?;
+    walks(network, &[start], |n| n == "ZZZ")
+}
+
+fn part_2(network: &Network) -> Result<u64, NetworkError> {
+    let starts: Vec<usize> = (0..network.nodes.len())
+        .filter(|&i| network.nodes[i].name.ends_with('A'))
+        .collect();
+    walks(network, &starts, |n| n.ends_with('Z'))
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_common::solution::Solution;
+
+    use crate::{part_1, part_2, Day8, Direction, NetworkError};
+
+    #[test]
+    fn test_part_1_1() {
+        let lines = [
+            "RL",
+            "",
+            "AAA = (BBB, CCC)",
+            "BBB = (DDD, EEE)",
+            "CCC = (ZZZ, GGG)",
+            "DDD = (DDD, DDD)",
+            "EEE = (EEE, EEE)",
+            "GGG = (GGG, GGG)",
+            "ZZZ = (ZZZ, ZZZ)",
+        ];
+        let network = Day8::parse(&lines.join("\n")).unwrap();
+
+        assert_eq!(part_1(&network).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_part_1_2() {
+        let lines = [
+            "LLR",
+            "",
+            "AAA = (BBB, BBB)",
+            "BBB = (AAA, ZZZ)",
+            "ZZZ = (ZZZ, ZZZ)",
+        ];
+        let network = Day8::parse(&lines.join("\n")).unwrap();
+
+        assert_eq!(part_1(&network).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let lines = [
+            "LR",
+            "",
+            "11A = (11B, XXX)",
+            "11B = (XXX, 11Z)",
+            "11Z = (11B, XXX)",
+            "22A = (22B, XXX)",
+            "22B = (22C, 22C)",
+            "22C = (22Z, 22Z)",
+            "22Z = (22B, 22B)",
+            "XXX = (XXX, XXX)",
+        ];
+        let network = Day8::parse(&lines.join("\n")).unwrap();
+
+        assert_eq!(part_2(&network).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = match Day8::parse("LRX\n\nAAA = (BBB, CCC)").err().unwrap() {
+            NetworkError::Parse(e) => e,
+            e => panic!("unexpected error {}", e),
+        };
+        assert_eq!((e.line, e.column, e.found.as_str()), (1, 3, "X"));
+
+        let e = match Day8::parse("LR\n\nAAA = (BBB, CCC)\nBBB = (DDD; EEE)")
+            .err()
+            .unwrap()
+        {
+            NetworkError::Parse(e) => e,
+            e => panic!("unexpected error {}", e),
+        };
+        assert_eq!((e.line, e.column, e.expected.as_str()), (4, 8, "','"));
+    }
+
+    #[test]
+    fn test_nodes() {
+        let network =
+            Day8::parse("L\n\nSTART = (B, START)\nB = (LONGEND, B)\nLONGEND = (B, B)").unwrap();
+        let start = network.find("START").unwrap();
+        let b = network.nodes()[start].next(Direction::Left);
+        assert_eq!(network.nodes()[b].name, "B");
+        assert_eq!(network.find("LONGEND"), Some(network.nodes()[b].next.0));
+        assert_eq!(network.find("LON"), None);
+
+        let e = match Day8::parse("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)")
+            .err()
+            .unwrap()
+        {
+            NetworkError::Parse(e) => e,
+            e => panic!("unexpected error {}", e),
+        };
+        assert_eq!((e.line, e.column, e.found.as_str()), (3, 13, "CCC"));
+
+        let e = match Day8::parse("LR\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)")
+            .err()
+            .unwrap()
+        {
+            NetworkError::Parse(e) => e,
+            e => panic!("unexpected error {}", e),
+        };
+        assert_eq!((e.line, e.column), (4, 1));
+    }
+}
@@ -1,167 +1,46 @@
-use std::{
-    collections::HashMap,
-    env,
-    fs::File,
-    io::{BufRead, BufReader, ErrorKind},
-};
-
-use num::Integer;
-
-struct Node {
-    name: String,
-    next: (String, String),
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc_common::solution;
+use clap::{error::ErrorKind, CommandFactory, Parser};
+use day8::Day8;
+
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    args: solution::Args,
+    /// Print the network as Graphviz DOT instead of solving
+    #[arg(long, conflicts_with = "format")]
+    dot: bool,
+    /// With --dot, highlight the path walked from this node
+    #[arg(long, value_name = "NODE", requires = "dot")]
+    from: Option<String>,
 }
 
-fn parse_nodes(lines: Vec<String>) -> HashMap<String, Node> {
-    let mut map = HashMap::new();
-
-    for line in lines {
-        let line = line.to_string();
-        let split = line.split_once('=').unwrap();
-        let from = split.0.trim()[0..3].to_string();
-        let to = &split.1.replace("(", "").replace(")", "").replace(" ", "");
-        let to = to.split_once(',').unwrap();
-        let to = (to.0[0..3].to_string(), to.1[0..3].to_string());
-
-        map.insert(
-            from.clone(),
-            Node {
-                name: from,
-                next: to,
-            },
-        );
-    }
-
-    map
+fn dot(paths: &[PathBuf], from: Option<&str>) -> ExitCode {
+    solution::for_each_input::<Day8>(paths, |name, network| {
+        let start = from
+            .map(|node| {
+                network
+                    .find(node)
+                    .ok_or_else(|| format!("{}: there is no node {}", name, node))
+            })
+            .transpose()?;
+        print!("{}", network.dot(start));
+        Ok(())
+    })
 }
 
-fn count_from_start(
-    directions: &Vec<char>,
-    nodes: &HashMap<String, Node>,
-    start: &str,
-    end_on_z_at_end: bool,
-) -> Option<u32> {
-    let mut node = nodes.get(start)?;
-    let mut count = 0;
-    let mut directions = directions.iter().cycle();
-
-    loop {
-        let dir = directions.next();
-        match dir? {
-            'L' => node = nodes.get(&node.next.0)?,
-            'R' => node = nodes.get(&node.next.1)?,
-            _ => unreachable!(),
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if cli.dot {
+        // a header naming each input would not be DOT
+        if cli.args.inputs.len() > 1 {
+            Cli::command()
+                .error(ErrorKind::TooManyValues, "--dot takes a single input")
+                .exit();
         }
-
-        count += 1;
-
-        if end_on_z_at_end && node.name.ends_with('Z') {
-            break;
-        } else if node.name == "ZZZ" {
-            break;
-        }
-    }
-
-    Some(count)
-}
-
-fn part_1(directions: &Vec<char>, nodes: &HashMap<String, Node>) -> Option<u32> {
-    count_from_start(directions, nodes, "AAA", false)
-}
-
-fn part_2(directions: &Vec<char>, nodes: &HashMap<String, Node>) -> Option<u64> {
-    Some(
-        nodes
-            .iter()
-            .filter(|(n, _)| n.ends_with('A'))
-            .map(|(n, _)| count_from_start(directions, nodes, n, true).unwrap() as u64)
-            .fold(1u64, |acc, elem| acc.lcm(&elem)),
-    )
-}
-
-fn main() -> std::io::Result<()> {
-    let filename = env::args().nth(1).ok_or(ErrorKind::Other)?;
-    let file = File::open(filename)?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines().map(|l| l.unwrap());
-    let directions: Vec<char> = lines.nth(0).unwrap().chars().collect();
-    let nodes = parse_nodes(lines.skip(1).collect());
-
-    println!("{}", part_1(&directions, &nodes).unwrap());
-    println!("{}", part_2(&directions, &nodes).unwrap());
-    Ok(())
-}
-
-#[cfg(test)]
-mod test {
-    use crate::{parse_nodes, part_1, part_2};
-
-    #[test]
-    fn test_part_1_1() {
-        let mut lines: Vec<String> = vec![
-            "RL",
-            "",
-            "AAA = (BBB, CCC)",
-            "BBB = (DDD, EEE)",
-            "CCC = (ZZZ, GGG)",
-            "DDD = (DDD, DDD)",
-            "EEE = (EEE, EEE)",
-            "GGG = (GGG, GGG)",
-            "ZZZ = (ZZZ, ZZZ)",
-        ]
-        .iter()
-        .map(|l| String::from(*l))
-        .collect();
-        let directions: Vec<char> = lines.get(0).unwrap().chars().collect();
-        lines.remove(0);
-        lines.remove(0);
-        let nodes = parse_nodes(lines);
-
-        assert_eq!(part_1(&directions, &nodes), Some(2));
-    }
-
-    #[test]
-    fn test_part_1_2() {
-        let mut lines: Vec<String> = vec![
-            "LLR",
-            "",
-            "AAA = (BBB, BBB)",
-            "BBB = (AAA, ZZZ)",
-            "ZZZ = (ZZZ, ZZZ)",
-        ]
-        .iter()
-        .map(|l| String::from(*l))
-        .collect();
-        let directions: Vec<char> = lines.get(0).unwrap().chars().collect();
-        lines.remove(0);
-        lines.remove(0);
-        let nodes = parse_nodes(lines);
-
-        assert_eq!(part_1(&directions, &nodes), Some(6));
-    }
-
-    #[test]
-    fn test_part_2() {
-        let mut lines: Vec<String> = vec![
-            "LR",
-            "",
-            "11A = (11B, XXX)",
-            "11B = (XXX, 11Z)",
-            "11Z = (11B, XXX)",
-            "22A = (22B, XXX)",
-            "22B = (22C, 22C)",
-            "22C = (22Z, 22Z)",
-            "22Z = (22B, 22B)",
-            "XXX = (XXX, XXX)",
-        ]
-        .iter()
-        .map(|l| String::from(*l))
-        .collect();
-        let directions: Vec<char> = lines.get(0).unwrap().chars().collect();
-        lines.remove(0);
-        lines.remove(0);
-        let nodes = parse_nodes(lines);
-
-        assert_eq!(part_2(&directions, &nodes), Some(6));
+        dot(&cli.args.inputs, cli.from.as_deref())
+    } else {
+        solution::main_with::<Day8>(&cli.args)
     }
 }
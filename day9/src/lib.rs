@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use aoc_common::{
+    parse::{number, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Sequence {
+    nums: Vec<i64>,
+}
+
+pub struct Day9;
+
+pub const EXAMPLE: &str = include_str!("../example.txt");
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Sequence>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .map(|l| Sequence::from_str(l).map_err(|e| e.within(input, l)))
+            .collect()
+    }
+
+    fn part1(sequences: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part1(sequences).into())
+    }
+
+    fn part2(sequences: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(part2(sequences).into())
+    }
+}
+
+fn part1(sequences: &[Sequence]) -> i64 {
+    sequences.iter().map(|s| s.next()).sum()
+}
+
+fn part2(sequences: &[Sequence]) -> i64 {
+    sequences.iter().map(|s| s.prev()).sum()
+}
+
+impl Sequence {
+    fn sequence_of_diffs(&self) -> Sequence {
+        let mut nums: Vec<i64> = vec![];
+
+        for i in 1..self.nums.len() {
+            let low = self.nums.get(i - 1).unwrap();
+            let high = self.nums.get(i).unwrap();
+
+            nums.push(high - low);
+        }
+
+        Self { nums }
+    }
+
+    fn is_identity(&self) -> bool {
+        self.nums.iter().all(|i| *i == 0)
+    }
+
+    fn next(&self) -> i64 {
+        if self.is_identity() {
+            0
+        } else {
+            self.nums.last().expect("no numbers in sequence") + self.sequence_of_diffs().next()
+        }
+    }
+
+    fn prev(&self) -> i64 {
+        if self.is_identity() {
+            0
+        } else {
+            self.nums.first().expect("no numbers in sequence") - self.sequence_of_diffs().prev()
+        }
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nums: Vec<i64> = s
+            .split_ascii_whitespace()
+            .map(|n| number(s, n))
+            .collect::<Result<_, _>>()?;
+        if nums.is_empty() {
+            return Err(ParseError::new(s, s, "a number"));
+        }
+
+        Ok(Self { nums })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use aoc_common::solution::Solution;
+
+    use crate::{part1, part2, Day9, Sequence};
+
+    #[test]
+    fn test_part1() {
+        let seqs: Vec<Sequence> = ["0 3 6 9 12 15", "1 3 6 10 15 21", "10 13 16 21 30 45"]
+            .iter()
+            .map(|l| Sequence::from_str(l).unwrap())
+            .collect();
+        assert_eq!(part1(&seqs), 114);
+    }
+
+    #[test]
+    fn test_part2() {
+        let seqs: Vec<Sequence> = ["0 3 6 9 12 15", "1 3 6 10 15 21", "10 13 16 21 30 45"]
+            .iter()
+            .map(|l| Sequence::from_str(l).unwrap())
+            .collect();
+        assert_eq!(part2(&seqs), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day9::parse("0 3 6\n1 3 six 10").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 5, "six"));
+
+        let e = Day9::parse("0 3 6\n\n1 3 6").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+}
@@ -1,6 +1,9 @@
 #!/bin/env sh
+cargo test --workspace &&
 for dir in day*; do
-        cd $dir &&
-        ./test &&
-        cd ..
+        if [ ! -f $dir/Cargo.toml ]; then
+                cd $dir &&
+                ./test &&
+                cd ..
+        fi
 done